@@ -0,0 +1,30 @@
+fn main() {
+    // Create a new client.
+    let mut abstractapi = abstractapi::AbstractApi::default();
+    abstractapi
+        .set_api_key(
+            abstractapi::ApiType::Avatars,
+            std::env::var("AVATARS_API_KEY").unwrap(),
+        )
+        .unwrap();
+
+    // Generate a rounded avatar with the initials of the given name.
+    let options = abstractapi::api::AvatarOptions {
+        image_size: Some(256),
+        background_color: Some(String::from("#335eea")),
+        font_color: Some(String::from("#ffffff")),
+        is_rounded: Some(true),
+        ..Default::default()
+    };
+    let avatar = abstractapi
+        .generate_avatar("Orhun Parmaksiz", &options)
+        .unwrap();
+
+    // Save the image into a file.
+    std::fs::write("avatar.png", &avatar.data).unwrap();
+    println!(
+        "Saved the avatar to avatar.png ({}, {} bytes)",
+        avatar.content_type,
+        avatar.data.len()
+    );
+}
@@ -1,3 +1,5 @@
+use abstractapi::api::CompanyLookup;
+
 fn main() {
     // Create a new client.
     let mut abstractapi = abstractapi::AbstractApi::default();
@@ -22,7 +24,7 @@ fn main() {
 
     // Get details about google.com domain.
     let company_details = abstractapi
-        .get_company_details(Some("google.com"), None)
+        .get_company_details(CompanyLookup::Domain("google.com".to_string()))
         .unwrap();
     print_pretty(company_details);
 
@@ -30,7 +32,7 @@ fn main() {
 
     // Get details about a facebook.com email.
     let company_details = abstractapi
-        .get_company_details(None, Some("mark@facebook.com"))
+        .get_company_details(CompanyLookup::Email("mark@facebook.com".to_string()))
         .unwrap();
     print_pretty(company_details);
 }
@@ -0,0 +1,23 @@
+fn main() {
+    // Create a new client.
+    let mut abstractapi = abstractapi::AbstractApi::default();
+    abstractapi
+        .set_api_key(
+            abstractapi::ApiType::EmailReputation,
+            std::env::var("EMAIL_REPUTATION_API_KEY").unwrap(),
+        )
+        .unwrap();
+
+    // Get the reputation of the given email address.
+    let email_reputation = abstractapi.get_email_reputation("test@gmail.com").unwrap();
+
+    // Print the result in a pretty format.
+    println!(
+        "Email: {}, deliverability: {}, score: {}, risk: {}, breaches: {}",
+        email_reputation.email_address,
+        email_reputation.email_deliverability.status,
+        email_reputation.email_quality.score.unwrap_or_default(),
+        email_reputation.email_risk.address_risk_status,
+        email_reputation.email_breaches.total_breaches,
+    );
+}
@@ -13,7 +13,7 @@ fn main() {
 
     // Print the result in a pretty format.
     println!(
-        "Email: {:?} (corrected: {:?}), status: {}, valid: {}, free: {}, disposable: {}, score: {}",
+        "Email: {} (corrected: {:?}), status: {}, valid: {}, free: {}, disposable: {}, score: {}",
         email_details.email,
         email_details.autocorrect,
         email_details.deliverability,
@@ -0,0 +1,24 @@
+fn main() {
+    // Create a new client.
+    let mut abstractapi = abstractapi::AbstractApi::default();
+    abstractapi
+        .set_api_key(
+            abstractapi::ApiType::IbanValidation,
+            std::env::var("IBAN_VALIDATION_API_KEY").unwrap(),
+        )
+        .unwrap();
+
+    // Get the IBAN details.
+    let iban_details = abstractapi.validate_iban("BE71 0961 2345 6769").unwrap();
+
+    // Print the result in a pretty format.
+    println!(
+        "IBAN: {}, valid: {}, BIC: {}",
+        iban_details.iban,
+        iban_details.is_valid,
+        iban_details
+            .bank_data
+            .and_then(|bank_data| bank_data.bic)
+            .unwrap_or_default(),
+    );
+}
@@ -0,0 +1,34 @@
+fn main() {
+    // Create a new client.
+    let mut abstractapi = abstractapi::AbstractApi::default();
+    abstractapi
+        .set_api_key(
+            abstractapi::ApiType::ImageProcessing,
+            std::env::var("IMAGE_PROCESSING_API_KEY").unwrap(),
+        )
+        .unwrap();
+
+    // Compress the image and crop its center.
+    let mut options = abstractapi::api::ImageOptions::new();
+    options.set_quality(75).set_crop(400, 400, "center");
+    let image = abstractapi
+        .process_image(
+            &abstractapi::api::ImageSource::Url(String::from(
+                "https://s3.amazonaws.com/static.abstractapi.com/test-images/dog.jpg",
+            )),
+            &options,
+        )
+        .unwrap();
+
+    // Print the result in a pretty format.
+    println!(
+        "Image: {}, Size: {} -> {} bytes, Dimensions: {}x{} -> {}x{}",
+        image.url,
+        image.original_size,
+        image.final_size,
+        image.original_width,
+        image.original_height,
+        image.final_width,
+        image.final_height,
+    );
+}
@@ -0,0 +1,23 @@
+fn main() {
+    // Create a new client.
+    let mut abstractapi = abstractapi::AbstractApi::default();
+    abstractapi
+        .set_api_key(
+            abstractapi::ApiType::IpIntelligence,
+            std::env::var("IP_INTELLIGENCE_API_KEY").unwrap(),
+        )
+        .unwrap();
+
+    // Get the intelligence data of the given IP address.
+    let ip_intelligence = abstractapi.get_ip_intelligence("172.217.19.142").unwrap();
+
+    // Print the result in a pretty format.
+    println!(
+        "IP: {}, ASN: {} ({}), anonymous: {}, hosting: {}",
+        ip_intelligence.ip_address,
+        ip_intelligence.asn.asn.unwrap_or_default(),
+        ip_intelligence.asn.name.unwrap_or_default(),
+        ip_intelligence.security.is_anonymous(),
+        ip_intelligence.security.is_hosting,
+    );
+}
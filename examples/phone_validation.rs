@@ -9,7 +9,7 @@ fn main() {
         .unwrap();
 
     // Get the phone number details.
-    let phone_details = abstractapi.validate_phone("14152007986").unwrap();
+    let phone_details = abstractapi.validate_phone("14152007986", None).unwrap();
 
     // Print the result in a pretty format.
     println!(
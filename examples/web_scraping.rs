@@ -0,0 +1,22 @@
+fn main() {
+    // Create a new client.
+    let mut abstractapi = abstractapi::AbstractApi::default();
+    abstractapi
+        .set_api_key(
+            abstractapi::ApiType::WebScraping,
+            std::env::var("WEB_SCRAPING_API_KEY").unwrap(),
+        )
+        .unwrap();
+
+    // Scrape the page from a proxy in the given country.
+    let options = abstractapi::api::ScrapeOptions {
+        proxy_country: Some(String::from("US")),
+        ..Default::default()
+    };
+    let html = abstractapi
+        .scrape("https://www.abstractapi.com", &options)
+        .unwrap();
+
+    // Print the result.
+    println!("Scraped {} bytes of HTML", html.len());
+}
@@ -0,0 +1,23 @@
+fn main() {
+    // Create a new client.
+    let mut abstractapi = abstractapi::AbstractApi::default();
+    abstractapi
+        .set_api_key(
+            abstractapi::ApiType::WebsiteScreenshot,
+            std::env::var("WEBSITE_SCREENSHOT_API_KEY").unwrap(),
+        )
+        .unwrap();
+
+    // Capture the full page and save it into a file.
+    let options = abstractapi::api::ScreenshotOptions {
+        capture_full_page: Some(true),
+        ..Default::default()
+    };
+    let mut file = std::fs::File::create("screenshot.png").unwrap();
+    let content_type = abstractapi
+        .capture_screenshot_to("https://www.abstractapi.com", &options, &mut file)
+        .unwrap();
+
+    // Print the result.
+    println!("Saved the screenshot to screenshot.png ({content_type})");
+}
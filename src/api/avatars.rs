@@ -0,0 +1,149 @@
+use crate::error::{Error, Result};
+
+/// Options of a generated avatar, the upstream defaults apply to unset options.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct AvatarOptions {
+    /// Size of the image in pixels (6-512).
+    pub image_size: Option<u32>,
+    /// Size of the font relative to the image (0.1-1.0).
+    pub font_size: Option<f32>,
+    /// Maximum number of initials (1-2).
+    pub char_limit: Option<u32>,
+    /// Hex color of the background (e.g. `#335eea`).
+    pub background_color: Option<String>,
+    /// Hex color of the initials (e.g. `#ffffff`).
+    pub font_color: Option<String>,
+    /// Whether the avatar is a circle instead of a square.
+    pub is_rounded: Option<bool>,
+    /// Whether the initials are uppercased.
+    pub is_uppercase: Option<bool>,
+    /// Whether the initials are italic.
+    pub is_italic: Option<bool>,
+    /// Whether the initials are bold.
+    pub is_bold: Option<bool>,
+}
+
+impl AvatarOptions {
+    /// Validates the ranges and colors of the options.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(image_size) = self.image_size {
+            if !(6..=512).contains(&image_size) {
+                return Err(Error::InvalidOptions(format!("image_size: {image_size}")));
+            }
+        }
+        if let Some(font_size) = self.font_size {
+            if !(0.1..=1.).contains(&font_size) {
+                return Err(Error::InvalidOptions(format!("font_size: {font_size}")));
+            }
+        }
+        if let Some(char_limit) = self.char_limit {
+            if !(1..=2).contains(&char_limit) {
+                return Err(Error::InvalidOptions(format!("char_limit: {char_limit}")));
+            }
+        }
+        for (name, color) in [
+            ("background_color", &self.background_color),
+            ("font_color", &self.font_color),
+        ] {
+            if let Some(color) = color {
+                if hex_color(color).is_none() {
+                    return Err(Error::InvalidOptions(format!("{name}: {color}")));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the query parameters of the options.
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut query_pairs = Vec::new();
+        if let Some(image_size) = self.image_size {
+            query_pairs.push(("image_size", image_size.to_string()));
+        }
+        if let Some(font_size) = self.font_size {
+            query_pairs.push(("font_size", font_size.to_string()));
+        }
+        if let Some(char_limit) = self.char_limit {
+            query_pairs.push(("char_limit", char_limit.to_string()));
+        }
+        if let Some(background_color) = self.background_color.as_deref().and_then(hex_color) {
+            query_pairs.push(("background_color", background_color));
+        }
+        if let Some(font_color) = self.font_color.as_deref().and_then(hex_color) {
+            query_pairs.push(("font_color", font_color));
+        }
+        for (name, flag) in [
+            ("is_rounded", self.is_rounded),
+            ("is_uppercase", self.is_uppercase),
+            ("is_italic", self.is_italic),
+            ("is_bold", self.is_bold),
+        ] {
+            if let Some(flag) = flag {
+                query_pairs.push((name, flag.to_string()));
+            }
+        }
+        query_pairs
+    }
+}
+
+/// Returns the hex color without the `#` prefix, expanding the shorthand form.
+fn hex_color(color: &str) -> Option<String> {
+    let color = color.trim().trim_start_matches('#');
+    if !color.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    match color.len() {
+        3 => Some(color.chars().flat_map(|c| [c, c]).collect()),
+        6 => Some(color.to_string()),
+        _ => None,
+    }
+    .map(|color| color.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_avatar_options() -> Result<()> {
+        let options = AvatarOptions {
+            image_size: Some(128),
+            background_color: Some(String::from("#FA0")),
+            font_color: Some(String::from("ffffff")),
+            is_rounded: Some(true),
+            ..AvatarOptions::default()
+        };
+        options.validate()?;
+        assert_eq!(
+            vec![
+                ("image_size", String::from("128")),
+                ("background_color", String::from("ffaa00")),
+                ("font_color", String::from("ffffff")),
+                ("is_rounded", String::from("true")),
+            ],
+            options.query_pairs()
+        );
+        for options in [
+            AvatarOptions {
+                image_size: Some(1024),
+                ..AvatarOptions::default()
+            },
+            AvatarOptions {
+                font_size: Some(0.),
+                ..AvatarOptions::default()
+            },
+            AvatarOptions {
+                char_limit: Some(3),
+                ..AvatarOptions::default()
+            },
+            AvatarOptions {
+                font_color: Some(String::from("white")),
+                ..AvatarOptions::default()
+            },
+        ] {
+            assert!(options.validate().is_err(), "{:?}", options);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+/// Binary response of an API that returns a file (e.g. an image).
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct BinaryResponse {
+    /// Content type of the data (e.g. `image/png`).
+    pub content_type: String,
+    /// Contents of the file.
+    pub data: Vec<u8>,
+}
@@ -2,6 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 
+mod lookup;
+
+pub use lookup::*;
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CompanyDetails {
@@ -0,0 +1,134 @@
+use crate::api::domain::to_ascii_domain;
+use crate::error::{Error, Result};
+
+/// Input of the company enrichment API.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompanyLookup {
+    /// Domain or URL of the company (e.g. `https://www.example.com/about`).
+    Domain(String),
+    /// Email address of someone working at the company.
+    Email(String),
+}
+
+impl CompanyLookup {
+    /// Returns the lookup for a value that may be a domain, a URL or an email address.
+    ///
+    /// Values with an `@` are email addresses, unless they are URLs with credentials
+    /// (e.g. `https://user@example.com`).
+    pub fn parse<S: Into<String>>(value: S) -> Self {
+        let value = value.into();
+        if value.contains('@') && !value.contains("://") {
+            Self::Email(value)
+        } else {
+            Self::Domain(value)
+        }
+    }
+
+    /// Returns the normalized lookup.
+    ///
+    /// Domains are stripped of the scheme, path, port and `www.` prefix, lowercased
+    /// and converted to punycode. Email addresses get the same treatment for their domain.
+    pub fn normalize(&self) -> Result<Self> {
+        match self {
+            Self::Domain(domain) => normalize_domain(domain)
+                .map(Self::Domain)
+                .ok_or_else(|| Error::InvalidCompanyLookup(domain.to_string())),
+            Self::Email(email) => normalize_email(email)
+                .map(Self::Email)
+                .ok_or_else(|| Error::InvalidCompanyLookup(email.to_string())),
+        }
+    }
+
+    /// Returns the normalized domain of the company.
+    pub fn domain(&self) -> Result<String> {
+        match self.normalize()? {
+            Self::Domain(domain) => Ok(domain),
+            Self::Email(email) => Ok(email
+                .rsplit_once('@')
+                .map(|(_, domain)| domain.to_string())
+                .unwrap_or(email)),
+        }
+    }
+}
+
+/// Normalizes a domain or URL into a punycode domain without `www.` prefix.
+fn normalize_domain(input: &str) -> Option<String> {
+    let mut domain = input.trim();
+    if let Some((_, rest)) = domain.split_once("://") {
+        domain = rest;
+    }
+    domain = domain.split(&['/', '?', '#'][..]).next()?;
+    domain = domain.rsplit('@').next()?;
+    domain = domain.split(':').next()?;
+    domain = domain.trim_end_matches('.');
+    let domain = to_ascii_domain(domain)?;
+    match domain.strip_prefix("www.") {
+        // The domain without the prefix must be valid on its own (e.g. not `www.com`).
+        Some(domain) => to_ascii_domain(domain),
+        None => Some(domain),
+    }
+}
+
+/// Normalizes the domain part of an email address.
+fn normalize_email(input: &str) -> Option<String> {
+    let (local, domain) = input.trim().rsplit_once('@')?;
+    if local.is_empty() || local.chars().any(char::is_whitespace) || domain.contains("://") {
+        return None;
+    }
+    Some(format!("{local}@{}", normalize_domain(domain)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_normalize() -> Result<()> {
+        for (input, expected) in [
+            ("google.com", "google.com"),
+            ("https://www.Google.com/about?hl=en", "google.com"),
+            ("HTTP://user@example.com:8080/", "example.com"),
+            ("bücher.de.", "xn--bcher-kva.de"),
+        ] {
+            assert_eq!(
+                CompanyLookup::Domain(expected.to_string()),
+                CompanyLookup::Domain(input.to_string()).normalize()?
+            );
+        }
+        let lookup = CompanyLookup::Email(String::from(" mark@WWW.Facebook.com "));
+        assert_eq!(
+            CompanyLookup::Email(String::from("mark@facebook.com")),
+            lookup.normalize()?
+        );
+        assert_eq!("facebook.com", lookup.domain()?);
+        for lookup in [
+            CompanyLookup::Domain(String::new()),
+            CompanyLookup::Domain(String::from("localhost")),
+            CompanyLookup::Domain(String::from("www.com")),
+            CompanyLookup::Email(String::from("mark@www.com")),
+            CompanyLookup::Domain(String::from("exa mple.com")),
+            CompanyLookup::Email(String::from("facebook.com")),
+            CompanyLookup::Email(String::from("@facebook.com")),
+        ] {
+            assert!(lookup.normalize().is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            CompanyLookup::Domain(String::from("https://www.example.com/about")),
+            CompanyLookup::parse("https://www.example.com/about")
+        );
+        assert_eq!(
+            CompanyLookup::Email(String::from("mark@facebook.com")),
+            CompanyLookup::parse("mark@facebook.com")
+        );
+        assert_eq!(
+            CompanyLookup::Domain(String::from("http://user@example.com")),
+            CompanyLookup::parse("http://user@example.com")
+        );
+    }
+}
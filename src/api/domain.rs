@@ -0,0 +1,21 @@
+/// Converts a domain name into punycode and validates its labels.
+///
+/// Returns `None` if the domain is not a valid hostname with at least two labels.
+pub(crate) fn to_ascii_domain(domain: &str) -> Option<String> {
+    let domain = idna::domain_to_ascii(domain).ok()?;
+    let labels = domain.split('.').collect::<Vec<&str>>();
+    let is_valid = domain.len() <= 253
+        && labels.len() >= 2
+        && !labels.last()?.chars().all(|c| c.is_ascii_digit())
+        && labels.iter().all(|label| {
+            (1..=63).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    if is_valid {
+        Some(domain)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,213 @@
+#![allow(missing_docs)]
+
+use super::Deliverability;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailReputation {
+    #[serde(rename = "email_address")]
+    pub email_address: String,
+    #[serde(rename = "email_deliverability", default)]
+    pub email_deliverability: EmailDeliverability,
+    #[serde(rename = "email_quality", default)]
+    pub email_quality: EmailQuality,
+    #[serde(rename = "email_sender", default)]
+    pub email_sender: EmailSender,
+    #[serde(rename = "email_domain", default)]
+    pub email_domain: EmailDomain,
+    #[serde(rename = "email_risk", default)]
+    pub email_risk: EmailRisk,
+    #[serde(rename = "email_breaches", default)]
+    pub email_breaches: EmailBreaches,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailDeliverability {
+    #[serde(default)]
+    pub status: Deliverability,
+    #[serde(rename = "status_detail")]
+    pub status_detail: Option<String>,
+    #[serde(rename = "is_format_valid", default)]
+    pub is_format_valid: bool,
+    #[serde(rename = "is_smtp_valid", default)]
+    pub is_smtp_valid: bool,
+    #[serde(rename = "is_mx_valid", default)]
+    pub is_mx_valid: bool,
+    #[serde(rename = "mx_records", default)]
+    pub mx_records: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailQuality {
+    pub score: Option<f32>,
+    #[serde(rename = "is_free_email")]
+    pub is_free_email: Option<bool>,
+    #[serde(rename = "is_username_suspicious")]
+    pub is_username_suspicious: Option<bool>,
+    #[serde(rename = "is_disposable")]
+    pub is_disposable: Option<bool>,
+    #[serde(rename = "is_catchall")]
+    pub is_catchall: Option<bool>,
+    #[serde(rename = "is_subaddress")]
+    pub is_subaddress: Option<bool>,
+    #[serde(rename = "is_role")]
+    pub is_role: Option<bool>,
+    #[serde(rename = "is_dmarc_enforced")]
+    pub is_dmarc_enforced: Option<bool>,
+    #[serde(rename = "is_spf_strict")]
+    pub is_spf_strict: Option<bool>,
+    #[serde(rename = "minimum_age")]
+    pub minimum_age: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailSender {
+    #[serde(rename = "first_name")]
+    pub first_name: Option<String>,
+    #[serde(rename = "last_name")]
+    pub last_name: Option<String>,
+    #[serde(rename = "email_provider_name")]
+    pub email_provider_name: Option<String>,
+    #[serde(rename = "organization_name")]
+    pub organization_name: Option<String>,
+    #[serde(rename = "organization_type")]
+    pub organization_type: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailDomain {
+    pub domain: Option<String>,
+    #[serde(rename = "domain_age")]
+    pub domain_age: Option<i64>,
+    #[serde(rename = "is_live_site")]
+    pub is_live_site: Option<bool>,
+    pub registrar: Option<String>,
+    #[serde(rename = "registrar_url")]
+    pub registrar_url: Option<String>,
+    #[serde(rename = "date_registered")]
+    pub date_registered: Option<String>,
+    #[serde(rename = "date_last_renewed")]
+    pub date_last_renewed: Option<String>,
+    #[serde(rename = "date_expires")]
+    pub date_expires: Option<String>,
+    #[serde(rename = "is_risky_tld")]
+    pub is_risky_tld: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailRisk {
+    #[serde(rename = "address_risk_status", default)]
+    pub address_risk_status: RiskLevel,
+    #[serde(rename = "domain_risk_status", default)]
+    pub domain_risk_status: RiskLevel,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailBreaches {
+    #[serde(rename = "total_breaches", default)]
+    pub total_breaches: u32,
+    #[serde(rename = "date_first_breached")]
+    pub date_first_breached: Option<String>,
+    #[serde(rename = "date_last_breached")]
+    pub date_last_breached: Option<String>,
+    #[serde(rename = "breached_domains", default)]
+    pub breached_domains: Vec<BreachedDomain>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreachedDomain {
+    pub domain: String,
+    #[serde(rename = "date_breached")]
+    pub date_breached: Option<String>,
+}
+
+/// Risk level of an email address or domain.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+    #[default]
+    Unknown,
+    /// Risk level that is not known by this library.
+    Other(String),
+}
+
+impl From<String> for RiskLevel {
+    fn from(value: String) -> Self {
+        match value.to_ascii_lowercase().as_str() {
+            "low" => Self::Low,
+            "medium" => Self::Medium,
+            "high" => Self::High,
+            "unknown" | "" => Self::Unknown,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<RiskLevel> for String {
+    fn from(risk_level: RiskLevel) -> Self {
+        risk_level.to_string()
+    }
+}
+
+impl fmt::Display for RiskLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Low => "low",
+                Self::Medium => "medium",
+                Self::High => "high",
+                Self::Unknown => "unknown",
+                Self::Other(value) => value,
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_deserialize_email_reputation() -> serde_json::Result<()> {
+        let email_reputation: EmailReputation = serde_json::from_str(
+            r#"{"email_address": "test@example.com",
+            "email_deliverability": {"status": "deliverable", "status_detail": "valid_email",
+            "is_format_valid": true, "is_smtp_valid": true, "is_mx_valid": true, "mx_records": []},
+            "email_quality": {"score": 0.8, "is_free_email": false, "minimum_age": null},
+            "email_risk": {"address_risk_status": "low", "domain_risk_status": "HIGH"},
+            "email_breaches": {"total_breaches": 2, "date_first_breached": "2019-01-01T00:00:00Z",
+            "date_last_breached": null, "breached_domains": [{"domain": "example.org",
+            "date_breached": "2019-01-01T00:00:00Z"}]}}"#,
+        )?;
+        assert_eq!(
+            Deliverability::Deliverable,
+            email_reputation.email_deliverability.status
+        );
+        assert_eq!(Some(0.8), email_reputation.email_quality.score);
+        assert_eq!(
+            (RiskLevel::Low, RiskLevel::High),
+            (
+                email_reputation.email_risk.address_risk_status,
+                email_reputation.email_risk.domain_risk_status
+            )
+        );
+        assert_eq!(2, email_reputation.email_breaches.total_breaches);
+        assert_eq!(None, email_reputation.email_sender.first_name);
+        Ok(())
+    }
+}
@@ -1,76 +1,120 @@
 #![allow(missing_docs)]
 
+use super::serde_utils::{empty_string_as_none, number_from_string};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Response types of the v2 endpoint.
+pub mod v2;
+
+mod precheck;
+
+pub use precheck::*;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EmailDetails {
     pub email: String,
-    pub autocorrect: String,
-    pub deliverability: String,
-    #[serde(rename = "quality_score")]
-    pub quality_score: String,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub autocorrect: Option<String>,
+    pub deliverability: Deliverability,
+    #[serde(rename = "quality_score", deserialize_with = "number_from_string")]
+    pub quality_score: f32,
     #[serde(rename = "is_valid_format")]
-    pub is_valid_format: IsValidFormat,
+    pub is_valid_format: EmailFlag,
     #[serde(rename = "is_free_email")]
-    pub is_free_email: IsFreeEmail,
+    pub is_free_email: EmailFlag,
     #[serde(rename = "is_disposable_email")]
-    pub is_disposable_email: IsDisposableEmail,
+    pub is_disposable_email: EmailFlag,
     #[serde(rename = "is_role_email")]
-    pub is_role_email: IsRoleEmail,
+    pub is_role_email: EmailFlag,
     #[serde(rename = "is_catchall_email")]
-    pub is_catchall_email: IsCatchallEmail,
+    pub is_catchall_email: EmailFlag,
     #[serde(rename = "is_mx_found")]
-    pub is_mx_found: IsMxFound,
+    pub is_mx_found: EmailFlag,
     #[serde(rename = "is_smtp_valid")]
-    pub is_smtp_valid: IsSmtpValid,
+    pub is_smtp_valid: EmailFlag,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct IsValidFormat {
-    pub value: bool,
-    pub text: String,
+/// Deliverability status of an email address.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Deliverability {
+    Deliverable,
+    Undeliverable,
+    #[default]
+    Unknown,
+    Risky,
+    /// Status that is not known by this library.
+    Other(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct IsFreeEmail {
-    pub value: bool,
-    pub text: String,
+impl From<String> for Deliverability {
+    fn from(value: String) -> Self {
+        match value.to_ascii_uppercase().as_str() {
+            "DELIVERABLE" => Self::Deliverable,
+            "UNDELIVERABLE" => Self::Undeliverable,
+            "UNKNOWN" => Self::Unknown,
+            "RISKY" => Self::Risky,
+            _ => Self::Other(value),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct IsDisposableEmail {
-    pub value: bool,
-    pub text: String,
+impl From<Deliverability> for String {
+    fn from(deliverability: Deliverability) -> Self {
+        deliverability.to_string()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct IsRoleEmail {
-    pub value: bool,
-    pub text: String,
+impl fmt::Display for Deliverability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Deliverable => "DELIVERABLE",
+                Self::Undeliverable => "UNDELIVERABLE",
+                Self::Unknown => "UNKNOWN",
+                Self::Risky => "RISKY",
+                Self::Other(value) => value,
+            }
+        )
+    }
 }
 
+/// Boolean check of an email address along with its textual representation.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct IsCatchallEmail {
+pub struct EmailFlag {
     pub value: bool,
     pub text: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct IsMxFound {
-    pub value: bool,
-    pub text: String,
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct IsSmtpValid {
-    pub value: bool,
-    pub text: String,
+    #[test]
+    fn test_deserialize_email_details() -> serde_json::Result<()> {
+        let flag = r#"{"value": true, "text": "TRUE"}"#;
+        let email_details: EmailDetails = serde_json::from_str(&format!(
+            r#"{{"email": "test@gmial.com", "autocorrect": "", "deliverability": "UNDELIVERABLE",
+            "quality_score": "0.70", "is_valid_format": {flag}, "is_free_email": {flag},
+            "is_disposable_email": {flag}, "is_role_email": {flag}, "is_catchall_email": {flag},
+            "is_mx_found": {flag}, "is_smtp_valid": {flag}}}"#
+        ))?;
+        assert_eq!(None, email_details.autocorrect);
+        assert_eq!(Deliverability::Undeliverable, email_details.deliverability);
+        assert_eq!(0.7, email_details.quality_score);
+        let email_details: EmailDetails =
+            serde_json::from_str(&serde_json::to_string(&email_details)?)?;
+        assert_eq!(Deliverability::Undeliverable, email_details.deliverability);
+        assert_eq!(
+            Deliverability::Other(String::from("CATCHALL")),
+            Deliverability::from(String::from("CATCHALL"))
+        );
+        Ok(())
+    }
 }
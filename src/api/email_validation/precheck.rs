@@ -0,0 +1,218 @@
+use super::{Deliverability, EmailDetails, EmailFlag};
+use crate::api::domain::to_ascii_domain;
+use std::collections::HashSet;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Bundled list of known disposable email domains.
+const DISPOSABLE_DOMAINS: &str = include_str!("disposable_domains.txt");
+
+/// Special characters that are allowed in the local part without quoting (RFC 5322 `atext`).
+const ATEXT_SPECIALS: &str = "!#$%&'*+-/=?^_`{|}~";
+
+/// Local check of email addresses that runs before calling the email validation API.
+///
+/// Addresses with invalid syntax (RFC 5322) or domain and, optionally, addresses
+/// with a known disposable domain get a locally built verdict, so the upstream
+/// call (and its credit) can be skipped. See [`AbstractApi::set_email_precheck`].
+///
+/// [`AbstractApi::set_email_precheck`]: crate::AbstractApi::set_email_precheck
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailPrecheck {
+    check_disposable: bool,
+    disposable_domains: HashSet<String>,
+}
+
+impl Default for EmailPrecheck {
+    fn default() -> Self {
+        Self {
+            check_disposable: true,
+            disposable_domains: DISPOSABLE_DOMAINS
+                .lines()
+                .map(str::trim)
+                .filter(|domain| !domain.is_empty())
+                .map(String::from)
+                .collect(),
+        }
+    }
+}
+
+impl EmailPrecheck {
+    /// Creates a new precheck with the bundled list of disposable domains.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the addresses with a known disposable domain are flagged.
+    pub fn set_check_disposable(&mut self, check_disposable: bool) {
+        self.check_disposable = check_disposable;
+    }
+
+    /// Adds domains to the list of disposable domains.
+    pub fn add_disposable_domains<I, S>(&mut self, domains: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.disposable_domains.extend(
+            domains
+                .into_iter()
+                .map(|domain| domain.as_ref().trim().to_ascii_lowercase()),
+        );
+    }
+
+    /// Returns whether the domain is a known disposable domain.
+    pub fn is_disposable_domain<S: AsRef<str>>(&self, domain: S) -> bool {
+        self.disposable_domains
+            .contains(&domain.as_ref().to_ascii_lowercase())
+    }
+
+    /// Checks the email address locally.
+    ///
+    /// Returns a verdict if the address fails the check, `None` if it should be
+    /// validated by the API.
+    pub fn check<S: AsRef<str>>(&self, email: S) -> Option<EmailDetails> {
+        let email = email.as_ref();
+        let domain = match parse_email(email) {
+            Some(domain) => domain,
+            None => {
+                return Some(EmailDetails {
+                    email: email.to_string(),
+                    deliverability: Deliverability::Undeliverable,
+                    is_valid_format: flag(false),
+                    ..verdict()
+                })
+            }
+        };
+        if self.check_disposable && self.is_disposable_domain(&domain) {
+            return Some(EmailDetails {
+                email: email.to_string(),
+                deliverability: Deliverability::Unknown,
+                is_valid_format: flag(true),
+                is_disposable_email: flag(true),
+                ..verdict()
+            });
+        }
+        None
+    }
+}
+
+/// Returns the base of a locally built verdict.
+fn verdict() -> EmailDetails {
+    EmailDetails {
+        quality_score: 0.,
+        is_valid_format: flag(false),
+        is_free_email: flag(false),
+        is_disposable_email: flag(false),
+        is_role_email: flag(false),
+        is_catchall_email: flag(false),
+        is_mx_found: flag(false),
+        is_smtp_valid: flag(false),
+        ..EmailDetails::default()
+    }
+}
+
+/// Returns a flag with the same text as the API.
+fn flag(value: bool) -> EmailFlag {
+    EmailFlag {
+        value,
+        text: String::from(if value { "TRUE" } else { "FALSE" }),
+    }
+}
+
+/// Parses the email address and returns its domain in punycode.
+fn parse_email(email: &str) -> Option<String> {
+    let (local, domain) = email.rsplit_once('@')?;
+    if !is_valid_local_part(local) {
+        return None;
+    }
+    let domain = if let Some(literal) = domain
+        .strip_prefix('[')
+        .and_then(|domain| domain.strip_suffix(']'))
+    {
+        let is_valid = match literal.strip_prefix("IPv6:") {
+            Some(address) => address.parse::<Ipv6Addr>().is_ok(),
+            None => literal.parse::<Ipv4Addr>().is_ok(),
+        };
+        Some(domain.to_string()).filter(|_| is_valid)?
+    } else if domain.ends_with('.') || domain.chars().any(char::is_whitespace) {
+        return None;
+    } else {
+        to_ascii_domain(domain)?
+    };
+    if local.len() + domain.len() + 1 > 254 {
+        return None;
+    }
+    Some(domain)
+}
+
+/// Returns whether the local part is a valid dot-atom or quoted string.
+fn is_valid_local_part(local: &str) -> bool {
+    if local.is_empty() || local.len() > 64 {
+        return false;
+    }
+    if let Some(quoted) = local
+        .strip_prefix('"')
+        .and_then(|local| local.strip_suffix('"'))
+    {
+        let mut chars = quoted.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' if chars.next().is_none() => return false,
+                '\\' => {}
+                '"' => return false,
+                c if c.is_ascii_control() => return false,
+                _ => {}
+            }
+        }
+        return true;
+    }
+    local.split('.').all(|atom| {
+        !atom.is_empty()
+            && atom
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || ATEXT_SPECIALS.contains(c) || !c.is_ascii())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_precheck() {
+        let precheck = EmailPrecheck::new();
+        for email in [
+            "test@gmail.com",
+            "first.last+tag@example.co.uk",
+            "\"john doe\"@example.com",
+            "user@bücher.de",
+            "user@[192.168.0.1]",
+        ] {
+            assert_eq!(None, precheck.check(email), "{email}");
+        }
+        for email in [
+            "",
+            "test",
+            "test@",
+            "@gmail.com",
+            "te st@gmail.com",
+            "test@gmail.com.",
+            "test.@gmail.com",
+            "te..st@gmail.com",
+            "test@localhost",
+            "test@-gmail.com",
+            "test@[300.0.0.1]",
+        ] {
+            let verdict = precheck.check(email).expect(email);
+            assert!(!verdict.is_valid_format.value);
+            assert_eq!(Deliverability::Undeliverable, verdict.deliverability);
+        }
+        let verdict = precheck.check("test@YOPmail.com").expect("disposable");
+        assert!(verdict.is_valid_format.value);
+        assert!(verdict.is_disposable_email.value);
+        let mut precheck = precheck;
+        precheck.set_check_disposable(false);
+        assert_eq!(None, precheck.check("test@yopmail.com"));
+    }
+}
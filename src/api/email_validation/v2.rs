@@ -0,0 +1,110 @@
+use crate::api::{
+    EmailBreaches, EmailDeliverability, EmailDomain, EmailFlag, EmailQuality, EmailRisk,
+    EmailSender,
+};
+use serde::{Deserialize, Serialize};
+
+/// Details of an email address that are returned by the v2 endpoint.
+///
+/// The sections are shared with the email reputation API. These details
+/// can be requested with [`AbstractApi::call_api`] after pinning v2, while
+/// [`AbstractApi::validate_email`] converts them into the v1 details.
+///
+/// [`AbstractApi::call_api`]: crate::AbstractApi::call_api
+/// [`AbstractApi::validate_email`]: crate::AbstractApi::validate_email
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmailDetails {
+    /// Email address that is validated.
+    pub email_address: String,
+    /// Suggested correction of a mistyped address.
+    #[serde(default)]
+    pub email_correction: Option<String>,
+    /// Deliverability of the address.
+    #[serde(default)]
+    pub email_deliverability: EmailDeliverability,
+    /// Quality checks of the address.
+    #[serde(default)]
+    pub email_quality: EmailQuality,
+    /// Sender that the address belongs to.
+    #[serde(default)]
+    pub email_sender: EmailSender,
+    /// Domain of the address.
+    #[serde(default)]
+    pub email_domain: EmailDomain,
+    /// Risk of the address and its domain.
+    #[serde(default)]
+    pub email_risk: EmailRisk,
+    /// Data breaches that the address appeared in.
+    #[serde(default)]
+    pub email_breaches: EmailBreaches,
+}
+
+impl From<EmailDetails> for super::EmailDetails {
+    fn from(email_details: EmailDetails) -> Self {
+        let flag = |value: bool| EmailFlag {
+            value,
+            text: value.to_string().to_uppercase(),
+        };
+        let quality = &email_details.email_quality;
+        let deliverability = &email_details.email_deliverability;
+        Self {
+            email: email_details.email_address.to_string(),
+            autocorrect: email_details.email_correction.clone(),
+            deliverability: deliverability.status.clone(),
+            quality_score: quality.score.unwrap_or_default(),
+            is_valid_format: flag(deliverability.is_format_valid),
+            is_free_email: flag(quality.is_free_email.unwrap_or_default()),
+            is_disposable_email: flag(quality.is_disposable.unwrap_or_default()),
+            is_role_email: flag(quality.is_role.unwrap_or_default()),
+            is_catchall_email: flag(quality.is_catchall.unwrap_or_default()),
+            is_mx_found: flag(deliverability.is_mx_valid),
+            is_smtp_valid: flag(deliverability.is_smtp_valid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{Deliverability, RiskLevel};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_deserialize_email_details() -> serde_json::Result<()> {
+        let email_details: EmailDetails = serde_json::from_str(
+            r#"{"email_address": "johnsmith@gmial.com", "email_correction": "johnsmith@gmail.com",
+            "email_deliverability": {"status": "undeliverable", "is_format_valid": true},
+            "email_quality": {"score": 0.1, "is_disposable": false},
+            "email_risk": {"address_risk_status": "high", "domain_risk_status": "medium"}}"#,
+        )?;
+        assert_eq!(
+            Some("johnsmith@gmail.com"),
+            email_details.email_correction.as_deref()
+        );
+        assert_eq!(
+            Deliverability::Undeliverable,
+            email_details.email_deliverability.status
+        );
+        assert_eq!(
+            RiskLevel::High,
+            email_details.email_risk.address_risk_status
+        );
+        assert_eq!(0, email_details.email_breaches.total_breaches);
+        let email_details = super::super::EmailDetails::from(email_details);
+        assert_eq!(
+            Some("johnsmith@gmail.com"),
+            email_details.autocorrect.as_deref()
+        );
+        assert_eq!(Deliverability::Undeliverable, email_details.deliverability);
+        assert_eq!(0.1, email_details.quality_score);
+        assert_eq!(
+            EmailFlag {
+                value: true,
+                text: String::from("TRUE")
+            },
+            email_details.is_valid_format
+        );
+        assert!(!email_details.is_disposable_email.value);
+        Ok(())
+    }
+}
@@ -2,6 +2,12 @@
 
 use serde::{Deserialize, Serialize};
 
+mod rate_table;
+mod series;
+
+pub use rate_table::*;
+pub use series::*;
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExchangeRatesResult {
@@ -106,6 +112,67 @@ pub struct ExchangeRates {
     pub ltc: Option<f64>,
 }
 
+impl ExchangeRates {
+    /// Returns an iterator over the present exchange rates as `(currency, rate)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, f64)> {
+        vec![
+            ("USD", self.usd),
+            ("EUR", self.eur),
+            ("JPY", self.jpy),
+            ("BGN", self.bgn),
+            ("CZK", self.czk),
+            ("DKK", self.dkk),
+            ("GBP", self.gbp),
+            ("HUF", self.huf),
+            ("PLN", self.pln),
+            ("RON", self.ron),
+            ("SEK", self.sek),
+            ("CHF", self.chf),
+            ("ISK", self.isk),
+            ("NOK", self.nok),
+            ("HRK", self.hrk),
+            ("RUB", self.rub),
+            ("TRY", self.try_field),
+            ("AUD", self.aud),
+            ("BRL", self.brl),
+            ("CAD", self.cad),
+            ("CNY", self.cny),
+            ("HKD", self.hkd),
+            ("IDR", self.idr),
+            ("ILS", self.ils),
+            ("INR", self.inr),
+            ("KRW", self.krw),
+            ("MXN", self.mxn),
+            ("MYR", self.myr),
+            ("NZD", self.nzd),
+            ("PHP", self.php),
+            ("SGD", self.sgd),
+            ("THB", self.thb),
+            ("ZAR", self.zar),
+            ("ARS", self.ars),
+            ("DZD", self.dzd),
+            ("MAD", self.mad),
+            ("TWD", self.twd),
+            ("BTC", self.btc),
+            ("ETH", self.eth),
+            ("BNB", self.bnb),
+            ("DOGE", self.doge),
+            ("XRP", self.xrp),
+            ("BCH", self.bch),
+            ("LTC", self.ltc),
+        ]
+        .into_iter()
+        .filter_map(|(currency, rate)| rate.map(|rate| (currency, rate)))
+    }
+
+    /// Returns the exchange rate for the given currency code.
+    pub fn get<S: AsRef<str>>(&self, currency: S) -> Option<f64> {
+        self.iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(currency.as_ref()))
+            .map(|(_, rate)| rate)
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConvertedExchangeRate {
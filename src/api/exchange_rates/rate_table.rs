@@ -0,0 +1,194 @@
+use super::ExchangeRatesResult;
+use crate::error::{Error, Result};
+use crate::rounding::RoundingMode;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Returns the ISO 4217 minor units (number of decimals) of a currency.
+///
+/// Cryptocurrencies are rounded to 8 decimals and unlisted currencies to 2.
+pub fn default_minor_units(currency: &str) -> u32 {
+    match currency.to_ascii_uppercase().as_str() {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        "BTC" | "ETH" | "BNB" | "DOGE" | "XRP" | "BCH" | "LTC" => 8,
+        _ => 2,
+    }
+}
+
+/// Offline currency converter built from a single exchange rates response.
+///
+/// Rates of a response are relative to its base currency, so conversions
+/// between two non-base currencies are triangulated through the base.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateTable {
+    base: String,
+    date: Option<String>,
+    last_updated: Option<i64>,
+    rates: HashMap<String, f64>,
+    minor_units: HashMap<String, Option<u32>>,
+    rounding_mode: RoundingMode,
+}
+
+impl From<&ExchangeRatesResult> for RateTable {
+    fn from(result: &ExchangeRatesResult) -> Self {
+        let base = result.base.to_ascii_uppercase();
+        let mut rates = result
+            .exchange_rates
+            .iter()
+            .map(|(currency, rate)| (currency.to_string(), rate))
+            .collect::<HashMap<String, f64>>();
+        rates.insert(base.clone(), 1.);
+        Self {
+            base,
+            date: result.date.clone(),
+            last_updated: result.last_updated,
+            rates,
+            minor_units: HashMap::new(),
+            rounding_mode: RoundingMode::default(),
+        }
+    }
+}
+
+impl From<ExchangeRatesResult> for RateTable {
+    fn from(result: ExchangeRatesResult) -> Self {
+        Self::from(&result)
+    }
+}
+
+impl RateTable {
+    /// Creates a new rate table from the given exchange rates response.
+    pub fn new(result: &ExchangeRatesResult) -> Self {
+        Self::from(result)
+    }
+
+    /// Returns the base currency of the table.
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Returns the date of the rates for historical responses.
+    pub fn date(&self) -> Option<&str> {
+        self.date.as_deref()
+    }
+
+    /// Returns the currencies that can be converted.
+    pub fn currencies(&self) -> impl Iterator<Item = &str> {
+        self.rates.keys().map(String::as_str)
+    }
+
+    /// Sets the rounding mode that is applied to converted amounts.
+    pub fn set_rounding_mode(&mut self, rounding_mode: RoundingMode) {
+        self.rounding_mode = rounding_mode;
+    }
+
+    /// Overrides the minor units of a currency.
+    ///
+    /// `None` disables rounding for amounts converted into the currency.
+    pub fn set_minor_units<S: AsRef<str>>(&mut self, currency: S, minor_units: Option<u32>) {
+        self.minor_units
+            .insert(currency.as_ref().to_ascii_uppercase(), minor_units);
+    }
+
+    /// Returns the minor units that converted amounts of a currency are rounded to.
+    pub fn minor_units<S: AsRef<str>>(&self, currency: S) -> Option<u32> {
+        let currency = currency.as_ref().to_ascii_uppercase();
+        match self.minor_units.get(&currency) {
+            Some(minor_units) => *minor_units,
+            None => Some(default_minor_units(&currency)),
+        }
+    }
+
+    /// Returns the exchange rate for converting `from` into `to`.
+    ///
+    /// Pairs that do not include the base currency are triangulated.
+    pub fn rate<S: AsRef<str>>(&self, from: S, to: S) -> Result<f64> {
+        let from_rate = self.base_rate(from.as_ref())?;
+        let to_rate = self.base_rate(to.as_ref())?;
+        Ok(to_rate / from_rate)
+    }
+
+    /// Converts an amount from `from` into `to` and rounds it to the minor units of `to`.
+    pub fn convert<S: AsRef<str>>(&self, amount: f64, from: S, to: S) -> Result<f64> {
+        let converted = amount * self.rate(from.as_ref(), to.as_ref())?;
+        Ok(match self.minor_units(to) {
+            Some(minor_units) => self.rounding_mode.round(converted, minor_units),
+            None => converted,
+        })
+    }
+
+    /// Returns how old the rates are, based on the `last_updated` field of the response.
+    ///
+    /// Returns `None` if the response did not contain a timestamp (e.g. historical rates).
+    pub fn age(&self) -> Option<Duration> {
+        self.age_at(SystemTime::now())
+    }
+
+    /// Returns how old the rates are at the given point in time.
+    ///
+    /// Returns `None` if the timestamp is negative or out of range.
+    pub fn age_at(&self, now: SystemTime) -> Option<Duration> {
+        let last_updated = u64::try_from(self.last_updated?).ok()?;
+        let last_updated = UNIX_EPOCH.checked_add(Duration::from_secs(last_updated))?;
+        Some(now.duration_since(last_updated).unwrap_or_default())
+    }
+
+    /// Returns the rate of a currency relative to the base currency.
+    fn base_rate(&self, currency: &str) -> Result<f64> {
+        self.rates
+            .get(&currency.to_ascii_uppercase())
+            .copied()
+            .filter(|rate| *rate > 0.)
+            .ok_or_else(|| Error::UnknownCurrency(currency.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ExchangeRates;
+    use pretty_assertions::assert_eq;
+
+    fn rate_table() -> RateTable {
+        RateTable::new(&ExchangeRatesResult {
+            base: String::from("USD"),
+            date: None,
+            last_updated: Some(1_600_000_000),
+            exchange_rates: ExchangeRates {
+                eur: Some(0.8),
+                jpy: Some(110.),
+                try_field: Some(7.5),
+                ..ExchangeRates::default()
+            },
+        })
+    }
+
+    #[test]
+    fn test_convert() -> Result<()> {
+        let mut rate_table = rate_table();
+        assert_eq!(8., rate_table.convert(10., "USD", "EUR")?);
+        assert_eq!(12.5, rate_table.convert(10., "eur", "usd")?);
+        assert_eq!(1375., rate_table.convert(10., "EUR", "JPY")?);
+        assert_eq!(0.07, rate_table.convert(1., "JPY", "TRY")?);
+        rate_table.set_minor_units("TRY", None);
+        assert_eq!(7.5 / 110., rate_table.convert(1., "JPY", "TRY")?);
+        assert!(matches!(
+            rate_table.convert(1., "USD", "GBP"),
+            Err(Error::UnknownCurrency(currency)) if currency == "GBP"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_age() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_600_000_060);
+        assert_eq!(Some(Duration::from_secs(60)), rate_table().age_at(now));
+        let mut rate_table = rate_table();
+        rate_table.last_updated = Some(-1);
+        assert_eq!(None, rate_table.age_at(now));
+        rate_table.last_updated = Some(i64::MAX);
+        assert_eq!(Some(Duration::ZERO), rate_table.age_at(now));
+    }
+}
@@ -0,0 +1,343 @@
+use super::ExchangeRates;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::io::Write;
+
+/// Policy for the dates that have no exchange rates, such as weekends and holidays.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GapPolicy {
+    /// Repeat the last known rates so that every date in the range is present.
+    ///
+    /// Weekends are filled without requesting the API.
+    #[default]
+    CarryForward,
+    /// Leave out weekends and the dates that are missing any of the requested rates.
+    Skip,
+}
+
+/// Exchange rates of a single date in a series.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeRatePoint {
+    /// Date of the rates (`YYYY-MM-DD`).
+    pub date: String,
+    /// Exchange rates relative to the base currency.
+    pub rates: BTreeMap<String, f64>,
+    /// Whether any of the rates are carried forward from a previous date.
+    pub carried_forward: bool,
+}
+
+/// Daily exchange rates over a date range.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeRateSeries {
+    /// Base currency.
+    pub base: String,
+    /// Requested target currencies, empty if all the currencies are requested.
+    pub targets: Vec<String>,
+    /// Exchange rates in chronological order.
+    pub points: Vec<ExchangeRatePoint>,
+}
+
+/// NDJSON record of an exchange rate point.
+#[derive(Serialize)]
+struct Record<'a> {
+    date: &'a str,
+    base: &'a str,
+    rates: &'a BTreeMap<String, f64>,
+    carried_forward: bool,
+}
+
+impl ExchangeRateSeries {
+    /// Builds the series by fetching the rates of each date with the given function.
+    ///
+    /// The function receives the comma separated target currencies and the date.
+    pub(crate) fn build<'a, F>(
+        base: &str,
+        targets: impl Iterator<Item = &'a str>,
+        start: &str,
+        end: &str,
+        gap_policy: GapPolicy,
+        mut fetch: F,
+    ) -> Result<Self>
+    where
+        F: FnMut(Option<&str>, &str) -> Result<ExchangeRates>,
+    {
+        let (start, end) = (start.parse::<Date>()?, end.parse::<Date>()?);
+        if start > end {
+            return Err(Error::InvalidDate(format!("{start} is after {end}")));
+        }
+        let mut series = Self {
+            base: base.to_ascii_uppercase(),
+            targets: targets.map(str::to_ascii_uppercase).collect(),
+            points: Vec::new(),
+        };
+        let target = Some(series.targets.join(",")).filter(|target| !target.is_empty());
+        let mut date = start;
+        while date <= end {
+            if date.is_weekend() {
+                match (gap_policy, series.points.last()) {
+                    (GapPolicy::Skip, _) => {
+                        date = date.next();
+                        continue;
+                    }
+                    (GapPolicy::CarryForward, Some(last_point)) => {
+                        let point = ExchangeRatePoint {
+                            date: date.to_string(),
+                            carried_forward: true,
+                            ..last_point.clone()
+                        };
+                        series.points.push(point);
+                        date = date.next();
+                        continue;
+                    }
+                    (GapPolicy::CarryForward, None) => {}
+                }
+            }
+            let exchange_rates = fetch(target.as_deref(), &date.to_string())?;
+            series.push(date, &exchange_rates, gap_policy);
+            date = date.next();
+        }
+        Ok(series)
+    }
+
+    /// Adds the rates of a date to the series according to the gap policy.
+    fn push(&mut self, date: Date, exchange_rates: &ExchangeRates, gap_policy: GapPolicy) {
+        let mut point = ExchangeRatePoint {
+            date: date.to_string(),
+            ..ExchangeRatePoint::default()
+        };
+        if self.targets.is_empty() {
+            point.rates = exchange_rates
+                .iter()
+                .map(|(currency, rate)| (currency.to_string(), rate))
+                .collect();
+        } else {
+            for target in &self.targets {
+                if let Some(rate) = exchange_rates.get(target) {
+                    point.rates.insert(target.to_string(), rate);
+                }
+            }
+        }
+        let is_missing = |point: &ExchangeRatePoint| {
+            point.rates.is_empty() || self.targets.iter().any(|t| !point.rates.contains_key(t))
+        };
+        if is_missing(&point) {
+            match (gap_policy, self.points.last()) {
+                (GapPolicy::Skip, _) => return,
+                (GapPolicy::CarryForward, Some(last_point)) => {
+                    for (currency, rate) in &last_point.rates {
+                        if !point.rates.contains_key(currency) {
+                            point.rates.insert(currency.to_string(), *rate);
+                            point.carried_forward = true;
+                        }
+                    }
+                }
+                (GapPolicy::CarryForward, None) => {}
+            }
+        }
+        self.points.push(point);
+    }
+
+    /// Returns the currencies that are present in the series.
+    pub fn currencies(&self) -> Vec<String> {
+        if self.targets.is_empty() {
+            self.points
+                .iter()
+                .flat_map(|point| point.rates.keys().cloned())
+                .collect::<BTreeSet<String>>()
+                .into_iter()
+                .collect()
+        } else {
+            self.targets.clone()
+        }
+    }
+
+    /// Writes the series as CSV with a column for each currency.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> Result<()> {
+        let currencies = self.currencies();
+        writeln!(writer, "date,{},carried_forward", currencies.join(","))?;
+        for point in &self.points {
+            let rates = currencies
+                .iter()
+                .map(|currency| {
+                    point
+                        .rates
+                        .get(currency)
+                        .map(f64::to_string)
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<String>>();
+            writeln!(
+                writer,
+                "{},{},{}",
+                point.date,
+                rates.join(","),
+                point.carried_forward
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes the series as newline delimited JSON with a record for each date.
+    pub fn write_ndjson<W: Write>(&self, mut writer: W) -> Result<()> {
+        for point in &self.points {
+            let record = Record {
+                date: &point.date,
+                base: &self.base,
+                rates: &point.rates,
+                carried_forward: point.carried_forward,
+            };
+            serde_json::to_writer(&mut writer, &record).map_err(std::io::Error::from)?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Calendar date in the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Date {
+    year: i64,
+    month: u32,
+    day: u32,
+}
+
+impl std::str::FromStr for Date {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let error = || Error::InvalidDate(s.to_string());
+        let mut parts = s.trim().splitn(3, '-');
+        let mut next = || -> Result<i64> {
+            parts
+                .next()
+                .and_then(|part| part.parse().ok())
+                .ok_or_else(error)
+        };
+        let (year, month, day) = (next()?, next()?, next()?);
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month as u32) as i64 {
+            return Err(error());
+        }
+        Ok(Self {
+            year,
+            month: month as u32,
+            day: day as u32,
+        })
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl Date {
+    /// Returns the following date.
+    fn next(self) -> Self {
+        if self.day < days_in_month(self.year, self.month) {
+            Self {
+                day: self.day + 1,
+                ..self
+            }
+        } else if self.month < 12 {
+            Self {
+                month: self.month + 1,
+                day: 1,
+                ..self
+            }
+        } else {
+            Self {
+                year: self.year + 1,
+                month: 1,
+                day: 1,
+            }
+        }
+    }
+
+    /// Returns whether the date is a Saturday or a Sunday.
+    fn is_weekend(self) -> bool {
+        // Sakamoto's method, 0 is Sunday.
+        const OFFSETS: [i64; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let year = if self.month < 3 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let weekday = (year + year / 4 - year / 100
+            + year / 400
+            + OFFSETS[self.month as usize - 1]
+            + self.day as i64)
+            .rem_euclid(7);
+        weekday == 0 || weekday == 6
+    }
+}
+
+/// Returns the number of days in the given month.
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_series() -> Result<()> {
+        let mut requested_dates = Vec::new();
+        let series = ExchangeRateSeries::build(
+            "usd",
+            vec!["EUR", "TRY"].into_iter(),
+            "2021-01-29",
+            "2021-02-02",
+            GapPolicy::CarryForward,
+            |target, date| {
+                assert_eq!(Some("EUR,TRY"), target);
+                requested_dates.push(date.to_string());
+                Ok(ExchangeRates {
+                    eur: Some(0.8),
+                    try_field: Some(7.).filter(|_| date != "2021-02-01"),
+                    ..ExchangeRates::default()
+                })
+            },
+        )?;
+        assert_eq!(
+            vec!["2021-01-29", "2021-02-01", "2021-02-02"],
+            requested_dates
+        );
+        let mut csv = Vec::new();
+        series.write_csv(&mut csv)?;
+        assert_eq!(
+            "date,EUR,TRY,carried_forward\n\
+            2021-01-29,0.8,7,false\n\
+            2021-01-30,0.8,7,true\n\
+            2021-01-31,0.8,7,true\n\
+            2021-02-01,0.8,7,true\n\
+            2021-02-02,0.8,7,false\n",
+            String::from_utf8_lossy(&csv)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_date() -> Result<()> {
+        assert_eq!(
+            "2021-03-01",
+            "2021-02-28".parse::<Date>()?.next().to_string()
+        );
+        assert_eq!(
+            "2021-01-01",
+            "2020-12-31".parse::<Date>()?.next().to_string()
+        );
+        assert!("2021-01-30".parse::<Date>()?.is_weekend());
+        assert!(!"2021-02-01".parse::<Date>()?.is_weekend());
+        assert!("2021-02-29".parse::<Date>().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+mod iban;
+
+pub use iban::*;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IbanDetails {
+    pub iban: String,
+    #[serde(rename = "is_valid")]
+    pub is_valid: bool,
+    #[serde(rename = "bank_data", default)]
+    pub bank_data: Option<BankData>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BankData {
+    #[serde(default)]
+    pub bic: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub city: Option<String>,
+    #[serde(default)]
+    pub zip: Option<String>,
+    #[serde(default)]
+    pub country: Option<String>,
+}
@@ -0,0 +1,90 @@
+use crate::error::{Error, Result};
+
+/// Returns the length of the IBANs of a country (ISO 3166-1 alpha-2 code).
+///
+/// Returns `None` for countries that are not in the IBAN registry.
+pub fn iban_length<S: AsRef<str>>(country_code: S) -> Option<usize> {
+    let length = match country_code.as_ref().to_ascii_uppercase().as_str() {
+        "NO" => 15,
+        "BE" => 16,
+        "DK" | "FI" | "FK" | "FO" | "GL" | "NL" | "SD" => 18,
+        "MK" | "SI" => 19,
+        "AT" | "BA" | "EE" | "KZ" | "LT" | "LU" | "MN" | "XK" => 20,
+        "CH" | "HR" | "LI" | "LV" => 21,
+        "BG" | "BH" | "CR" | "DE" | "GB" | "GE" | "IE" | "ME" | "RS" | "VA" => 22,
+        "AE" | "GI" | "IL" | "IQ" | "OM" | "SO" | "TL" => 23,
+        "AD" | "CZ" | "ES" | "MD" | "PK" | "RO" | "SA" | "SE" | "SK" | "TN" | "VG" => 24,
+        "LY" | "PT" | "ST" => 25,
+        "IS" | "TR" => 26,
+        "BI" | "DJ" | "FR" | "GR" | "IT" | "MC" | "MR" | "SM" => 27,
+        "AL" | "AZ" | "BY" | "CY" | "DO" | "GT" | "HU" | "LB" | "NI" | "PL" | "SV" => 28,
+        "BR" | "EG" | "PS" | "QA" | "UA" => 29,
+        "JO" | "KW" | "MU" | "YE" => 30,
+        "MT" | "SC" => 31,
+        "LC" => 32,
+        "RU" => 33,
+        _ => return None,
+    };
+    Some(length)
+}
+
+/// Normalizes an IBAN and validates it locally.
+///
+/// Spaces and dashes are removed and the IBAN is uppercased
+/// (e.g. `be71 0961 2345 6769` becomes `BE71096123456769`).
+/// The length is checked against the country and the check digits
+/// are validated with the ISO 7064 mod 97-10 algorithm.
+pub fn normalize_iban<S: AsRef<str>>(iban: S) -> Result<String> {
+    let iban = iban.as_ref();
+    let normalized = iban
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_ascii_uppercase();
+    let is_valid = normalized.is_ascii()
+        && normalized.chars().all(|c| c.is_ascii_alphanumeric())
+        && iban_length(normalized.get(..2).unwrap_or_default()) == Some(normalized.len())
+        && normalized[2..4].chars().all(|c| c.is_ascii_digit())
+        && normalized[4..]
+            .chars()
+            .chain(normalized[..4].chars())
+            .filter_map(|c| c.to_digit(36))
+            .fold(0, |remainder, value| {
+                let factor = if value < 10 { 10 } else { 100 };
+                (remainder * factor + value) % 97
+            })
+            == 1;
+    if is_valid {
+        Ok(normalized)
+    } else {
+        Err(Error::InvalidIban(iban.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_normalize_iban() -> Result<()> {
+        assert_eq!("BE71096123456769", normalize_iban("be71 0961 2345 6769")?);
+        assert_eq!(
+            "GB82WEST12345698765432",
+            normalize_iban("GB82 WEST 1234 5698 7654 32")?
+        );
+        assert_eq!("NO9386011117947", normalize_iban("NO93-8601-1117-947")?);
+        for iban in [
+            "",
+            "BE",
+            "BE71096123456768",
+            "BE7109612345676",
+            "XX71096123456769",
+            "DE89370400440532013001",
+            "GB82WEST1234569876543É",
+        ] {
+            assert!(normalize_iban(iban).is_err(), "{}", iban);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+#![allow(missing_docs)]
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+
+/// Image that is processed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageSource {
+    /// URL of the image.
+    Url(String),
+    /// Image that is uploaded.
+    Upload {
+        /// File name of the image.
+        file_name: String,
+        /// Contents of the image.
+        data: Vec<u8>,
+    },
+}
+
+/// Strategy for resizing an image.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResizeStrategy {
+    /// Resize to the exact width and height, ignoring the aspect ratio.
+    Exact,
+    /// Resize to the height, keeping the aspect ratio.
+    Portrait,
+    /// Resize to the width, keeping the aspect ratio.
+    Landscape,
+    /// Resize to the width or height based on the orientation of the image.
+    #[default]
+    Auto,
+    /// Crop and resize to fit the width and height.
+    Fit,
+    /// Crop to a square and resize to the width.
+    Square,
+    /// Resize to fit the width and height, filling the rest with the background.
+    Fill,
+    /// Crop the part of the image with the width and height.
+    Crop,
+}
+
+/// Resize or crop options of an image.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Resize {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    pub strategy: ResizeStrategy,
+    /// Part of the image that is kept when cropping (e.g. `center`, `n`, `se`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crop_mode: Option<String>,
+    /// Background color for the `fill` strategy (e.g. `#ffffff` or `auto`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+}
+
+/// Options of an image processing request, the upstream defaults apply to unset options.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lossy: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quality: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resize: Option<Resize>,
+}
+
+impl ImageOptions {
+    /// Creates new options that keep the image as it is.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the image is compressed lossily.
+    pub fn set_lossy(&mut self, lossy: bool) -> &mut Self {
+        self.lossy = Some(lossy);
+        self
+    }
+
+    /// Sets the quality (1-100) of the lossy compression.
+    pub fn set_quality(&mut self, quality: u8) -> &mut Self {
+        self.lossy = Some(true);
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Sets the resize options.
+    pub fn set_resize(&mut self, resize: Resize) -> &mut Self {
+        self.resize = Some(resize);
+        self
+    }
+
+    /// Crops the part of the image with the given size around the given position.
+    pub fn set_crop<S: Into<String>>(
+        &mut self,
+        width: u32,
+        height: u32,
+        crop_mode: S,
+    ) -> &mut Self {
+        self.set_resize(Resize {
+            width: Some(width),
+            height: Some(height),
+            strategy: ResizeStrategy::Crop,
+            crop_mode: Some(crop_mode.into()),
+            background: None,
+        })
+    }
+
+    /// Validates the options.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(quality) = self.quality {
+            if !(1..=100).contains(&quality) {
+                return Err(Error::InvalidOptions(format!("quality: {quality}")));
+            }
+        }
+        if let Some(resize) = &self.resize {
+            let (width, height) = (resize.width.unwrap_or(1), resize.height.unwrap_or(1));
+            if width == 0 || height == 0 {
+                return Err(Error::InvalidOptions(format!("size: {width}x{height}")));
+            }
+            let needs_both = !matches!(
+                resize.strategy,
+                ResizeStrategy::Portrait | ResizeStrategy::Landscape | ResizeStrategy::Auto
+            );
+            if resize.width.is_none() && resize.height.is_none()
+                || needs_both && (resize.width.is_none() || resize.height.is_none())
+            {
+                return Err(Error::InvalidOptions(format!(
+                    "missing size for {:?}",
+                    resize.strategy
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the JSON body of a request with the options.
+    pub(crate) fn to_json(&self, api_key: &str, url: Option<&str>) -> Result<serde_json::Value> {
+        let mut body = serde_json::to_value(self).map_err(std::io::Error::from)?;
+        if let Some(object) = body.as_object_mut() {
+            object.insert(String::from("api_key"), api_key.into());
+            if let Some(url) = url {
+                object.insert(String::from("url"), url.into());
+            }
+        }
+        Ok(body)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessedImage {
+    #[serde(rename = "original_size")]
+    pub original_size: u64,
+    #[serde(rename = "original_height")]
+    pub original_height: u32,
+    #[serde(rename = "original_width")]
+    pub original_width: u32,
+    #[serde(rename = "final_size")]
+    pub final_size: u64,
+    #[serde(rename = "bytes_saved")]
+    pub bytes_saved: i64,
+    #[serde(rename = "final_height")]
+    pub final_height: u32,
+    #[serde(rename = "final_width")]
+    pub final_width: u32,
+    pub url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_image_options() -> Result<()> {
+        let mut options = ImageOptions::new();
+        options.set_quality(80).set_crop(200, 100, "center");
+        options.validate()?;
+        assert_eq!(
+            serde_json::json!({
+                "api_key": "key",
+                "url": "https://example.com/cat.png",
+                "lossy": true,
+                "quality": 80,
+                "resize": {"width": 200, "height": 100, "strategy": "crop", "crop_mode": "center"},
+            }),
+            options.to_json("key", Some("https://example.com/cat.png"))?
+        );
+        assert!(ImageOptions::new().set_quality(0).validate().is_err());
+        assert!(ImageOptions::new()
+            .set_resize(Resize {
+                width: Some(100),
+                strategy: ResizeStrategy::Exact,
+                ..Resize::default()
+            })
+            .validate()
+            .is_err());
+        Ok(())
+    }
+}
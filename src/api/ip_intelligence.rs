@@ -0,0 +1,119 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpIntelligence {
+    #[serde(rename = "ip_address")]
+    pub ip_address: String,
+    #[serde(default)]
+    pub security: IpSecurity,
+    #[serde(default)]
+    pub asn: Asn,
+    #[serde(default)]
+    pub company: Option<IpCompany>,
+    #[serde(default)]
+    pub location: IpLocation,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpSecurity {
+    #[serde(rename = "is_vpn", default)]
+    pub is_vpn: bool,
+    #[serde(rename = "is_proxy", default)]
+    pub is_proxy: bool,
+    #[serde(rename = "is_tor", default)]
+    pub is_tor: bool,
+    #[serde(rename = "is_hosting", default)]
+    pub is_hosting: bool,
+    #[serde(rename = "is_relay", default)]
+    pub is_relay: bool,
+    #[serde(rename = "is_mobile", default)]
+    pub is_mobile: bool,
+    #[serde(rename = "is_abuse", default)]
+    pub is_abuse: bool,
+}
+
+impl IpSecurity {
+    /// Returns whether the IP address hides the real location of the user
+    /// (VPN, proxy, Tor or relay).
+    pub fn is_anonymous(&self) -> bool {
+        self.is_vpn || self.is_proxy || self.is_tor || self.is_relay
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpCompany {
+    pub name: Option<String>,
+    pub domain: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpLocation {
+    pub city: Option<String>,
+    pub region: Option<String>,
+    pub country: Option<String>,
+    #[serde(rename = "country_code")]
+    pub country_code: Option<String>,
+    pub continent: Option<String>,
+    pub longitude: Option<f64>,
+    pub latitude: Option<f64>,
+}
+
+/// Autonomous system of an IP address.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Asn {
+    pub asn: Option<i64>,
+    pub name: Option<String>,
+    pub domain: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_deserialize_ip_intelligence() -> serde_json::Result<()> {
+        let ip_intelligence: IpIntelligence = serde_json::from_str(
+            r#"{"ip_address": "1.1.1.1",
+            "security": {"is_vpn": false, "is_proxy": false, "is_tor": false, "is_hosting": true,
+            "is_relay": false, "is_mobile": false, "is_abuse": false},
+            "asn": {"asn": 13335, "name": "Cloudflare, Inc.", "domain": "cloudflare.com",
+            "type": "business"},
+            "company": {"name": "APNIC and Cloudflare DNS Resolver project",
+            "domain": "cloudflare.com", "type": "hosting"},
+            "domain": {"domain": "one.one", "tld": "one", "name": "one"},
+            "location": {"city": "Brisbane", "region": "Queensland", "region_iso_code": "QLD",
+            "postal_code": "4007", "country": "Australia", "country_code": "AU",
+            "continent": "Oceania", "continent_code": "OC", "latitude": -27.467,
+            "longitude": 153.028, "is_eu_member": false},
+            "timezone": {"name": "Australia/Brisbane", "abbreviation": "AEST", "utc_offset": 10,
+            "local_time": "19:17:43", "is_dst": false}}"#,
+        )?;
+        assert_eq!("1.1.1.1", ip_intelligence.ip_address);
+        assert!(ip_intelligence.security.is_hosting);
+        assert!(!ip_intelligence.security.is_anonymous());
+        assert_eq!(Some(13335), ip_intelligence.asn.asn);
+        assert_eq!(Some("business"), ip_intelligence.asn.type_.as_deref());
+        assert_eq!(
+            Some("hosting"),
+            ip_intelligence
+                .company
+                .as_ref()
+                .and_then(|company| company.type_.as_deref())
+        );
+        assert_eq!(Some("AU"), ip_intelligence.location.country_code.as_deref());
+        assert_eq!(Some(-27.467), ip_intelligence.location.latitude);
+        Ok(())
+    }
+}
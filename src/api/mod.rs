@@ -1,5 +1,9 @@
+/// Avatars API.
+pub mod avatars;
 /// Company details API.
 pub mod company_enrichment;
+/// Email reputation API.
+pub mod email_reputation;
 /// Email validation API.
 pub mod email_validation;
 /// Exchange rates API.
@@ -8,19 +12,42 @@ pub mod exchange_rates;
 pub mod geolocation;
 /// Holidays API.
 pub mod holidays;
+/// IBAN validation API.
+pub mod iban_validation;
+/// Image processing API.
+pub mod image_processing;
+/// IP intelligence API.
+pub mod ip_intelligence;
 /// Phone validation API.
 pub mod phone_validation;
 /// Timezone API.
 pub mod timezone;
 /// VAT API.
 pub mod vat;
+/// Web scraping API.
+pub mod web_scraping;
+/// Website screenshot API.
+pub mod website_screenshot;
+
+mod binary;
+mod domain;
+mod serde_utils;
 
 /// Export API types for convenience.
+pub use avatars::*;
 pub use company_enrichment::*;
+pub use email_reputation::*;
 pub use email_validation::*;
 pub use exchange_rates::*;
 pub use geolocation::*;
 pub use holidays::*;
+pub use iban_validation::*;
+pub use image_processing::*;
+pub use ip_intelligence::*;
 pub use phone_validation::*;
 pub use timezone::*;
 pub use vat::*;
+pub use web_scraping::*;
+pub use website_screenshot::*;
+
+pub use binary::*;
@@ -1,6 +1,11 @@
 #![allow(missing_docs)]
 
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+mod e164;
+
+pub use e164::*;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -11,7 +16,7 @@ pub struct PhoneDetails {
     pub country: Country,
     pub location: String,
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: LineType,
     pub carrier: String,
 }
 
@@ -29,3 +34,66 @@ pub struct Country {
     pub name: String,
     pub prefix: String,
 }
+
+/// Line type of a phone number.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum LineType {
+    Mobile,
+    Landline,
+    Voip,
+    TollFree,
+    Premium,
+    #[default]
+    Unknown,
+    /// Line type that is not known by this library.
+    Other(String),
+}
+
+impl From<String> for LineType {
+    fn from(value: String) -> Self {
+        match value
+            .to_ascii_lowercase()
+            .replace(&[' ', '-'][..], "_")
+            .as_str()
+        {
+            "mobile" => Self::Mobile,
+            "landline" => Self::Landline,
+            "voip" => Self::Voip,
+            "toll_free" => Self::TollFree,
+            "premium" => Self::Premium,
+            "unknown" | "" => Self::Unknown,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<&str> for LineType {
+    fn from(value: &str) -> Self {
+        Self::from(value.to_string())
+    }
+}
+
+impl From<LineType> for String {
+    fn from(line_type: LineType) -> Self {
+        line_type.to_string()
+    }
+}
+
+impl fmt::Display for LineType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Mobile => "mobile",
+                Self::Landline => "landline",
+                Self::Voip => "voip",
+                Self::TollFree => "toll_free",
+                Self::Premium => "premium",
+                Self::Unknown => "unknown",
+                Self::Other(value) => value,
+            }
+        )
+    }
+}
@@ -0,0 +1,65 @@
+use crate::error::{Error, Result};
+
+/// Maximum number of digits in an E.164 phone number.
+const MAX_DIGITS: usize = 15;
+
+/// Minimum number of digits that is accepted as a phone number.
+const MIN_DIGITS: usize = 5;
+
+/// Normalizes a phone number by removing the formatting characters.
+///
+/// Spaces, parentheses, dashes, dots and slashes are removed and the `00`
+/// international call prefix is replaced with `+`, so international numbers
+/// are returned in the E.164 format (e.g. `+14152007986`). Numbers without
+/// an international prefix are returned as digits.
+pub fn normalize_phone_number<S: AsRef<str>>(phone: S) -> Result<String> {
+    let phone = phone.as_ref();
+    let error = || Error::InvalidPhoneNumber(phone.to_string());
+    let stripped = phone
+        .trim()
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '(' | ')' | '-' | '.' | '/'))
+        .collect::<String>();
+    let (prefix, digits) = if let Some(digits) = stripped.strip_prefix('+') {
+        ("+", digits)
+    } else if let Some(digits) = stripped.strip_prefix("00") {
+        ("+", digits)
+    } else {
+        ("", stripped.as_str())
+    };
+    if !digits.chars().all(|c| c.is_ascii_digit())
+        || !(MIN_DIGITS..=MAX_DIGITS).contains(&digits.len())
+        || (prefix == "+" && digits.starts_with('0'))
+    {
+        return Err(error());
+    }
+    Ok(format!("{prefix}{digits}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_normalize_phone_number() -> Result<()> {
+        assert_eq!("14152007986", normalize_phone_number("14152007986")?);
+        assert_eq!("+14152007986", normalize_phone_number("+1 (415) 200-7986")?);
+        assert_eq!(
+            "+905321234567",
+            normalize_phone_number("0090 532 123 45 67")?
+        );
+        assert_eq!("05321234567", normalize_phone_number("0532.123.45.67")?);
+        for phone in [
+            "",
+            "+",
+            "1234",
+            "+1 415 CALL NOW",
+            "+0123456789",
+            "1234567890123456",
+        ] {
+            assert!(normalize_phone_number(phone).is_err(), "{}", phone);
+        }
+        Ok(())
+    }
+}
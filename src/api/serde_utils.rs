@@ -0,0 +1,32 @@
+use serde::{Deserialize, Deserializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Value that is returned either as a string or as a number.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrNumber<T> {
+    String(String),
+    Number(T),
+}
+
+/// Deserializes a number that may be returned as a string (e.g. `"0.80"`).
+pub(crate) fn number_from_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+{
+    match StringOrNumber::<T>::deserialize(deserializer)? {
+        StringOrNumber::String(value) => value.trim().parse().map_err(serde::de::Error::custom),
+        StringOrNumber::Number(value) => Ok(value),
+    }
+}
+
+/// Deserializes an empty or missing string as `None`.
+pub(crate) fn empty_string_as_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.filter(|value| !value.trim().is_empty()))
+}
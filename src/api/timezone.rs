@@ -2,6 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "chrono")]
+mod datetime;
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LocationTime {
@@ -30,3 +33,30 @@ pub struct ConvertedTime {
     #[serde(rename = "target_location")]
     pub target_location: LocationTime,
 }
+
+/// Format of the date and time values in the timezone API.
+pub const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Date and time that can be passed to the timezone API.
+pub trait ApiDateTime {
+    /// Returns the date and time in the format that the API expects.
+    fn to_api_datetime(&self) -> String;
+}
+
+impl ApiDateTime for str {
+    fn to_api_datetime(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ApiDateTime for String {
+    fn to_api_datetime(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<T: ApiDateTime + ?Sized> ApiDateTime for &T {
+    fn to_api_datetime(&self) -> String {
+        (*self).to_api_datetime()
+    }
+}
@@ -0,0 +1,63 @@
+use super::{ApiDateTime, LocationTime, DATETIME_FORMAT};
+use crate::error::{Error, Result};
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+use std::convert::TryFrom;
+
+impl ApiDateTime for NaiveDateTime {
+    fn to_api_datetime(&self) -> String {
+        self.format(DATETIME_FORMAT).to_string()
+    }
+}
+
+impl LocationTime {
+    /// Returns the date and time of the location with its GMT offset.
+    pub fn to_datetime(&self) -> Result<DateTime<FixedOffset>> {
+        let error = || Error::InvalidDate(self.datetime.to_string());
+        let datetime =
+            NaiveDateTime::parse_from_str(&self.datetime, DATETIME_FORMAT).map_err(|_| error())?;
+        FixedOffset::east_opt((self.gmt_offset * 3600.).round() as i32)
+            .and_then(|offset| offset.from_local_datetime(&datetime).single())
+            .ok_or_else(error)
+    }
+
+    /// Returns the IANA timezone of the location.
+    pub fn to_timezone(&self) -> Result<Tz> {
+        self.timezone_location
+            .parse()
+            .map_err(|_| Error::InvalidTimezone(self.timezone_location.to_string()))
+    }
+}
+
+impl TryFrom<&LocationTime> for DateTime<FixedOffset> {
+    type Error = Error;
+    fn try_from(location_time: &LocationTime) -> Result<Self> {
+        location_time.to_datetime()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_location_time() -> Result<()> {
+        let location_time = LocationTime {
+            datetime: String::from("2020-05-01 07:00:00"),
+            timezone_location: String::from("Asia/Kolkata"),
+            gmt_offset: 5.5,
+            ..LocationTime::default()
+        };
+        assert_eq!(
+            "2020-05-01T07:00:00+05:30",
+            location_time.to_datetime()?.to_rfc3339()
+        );
+        assert_eq!(Tz::Asia__Kolkata, location_time.to_timezone()?);
+        assert_eq!(
+            "2020-05-01 07:00:00",
+            location_time.to_datetime()?.naive_local().to_api_datetime()
+        );
+        Ok(())
+    }
+}
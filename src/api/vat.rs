@@ -1,6 +1,19 @@
 #![allow(missing_docs)]
 
+use super::serde_utils::number_from_string;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Deref;
+
+mod cross_border;
+mod invoice;
+mod number;
+mod table;
+
+pub use cross_border::*;
+pub use invoice::*;
+pub use number::*;
+pub use table::*;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -29,27 +42,237 @@ pub struct Country {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Vat {
-    #[serde(rename = "amount_excluding_vat")]
-    pub amount_excluding_vat: String,
-    #[serde(rename = "amount_including_vat")]
-    pub amount_including_vat: String,
-    #[serde(rename = "vat_amount")]
-    pub vat_amount: String,
+    #[serde(
+        rename = "amount_excluding_vat",
+        deserialize_with = "number_from_string"
+    )]
+    pub amount_excluding_vat: f64,
+    #[serde(
+        rename = "amount_including_vat",
+        deserialize_with = "number_from_string"
+    )]
+    pub amount_including_vat: f64,
+    #[serde(rename = "vat_amount", deserialize_with = "number_from_string")]
+    pub vat_amount: f64,
     #[serde(rename = "vat_category")]
-    pub vat_category: String,
-    #[serde(rename = "vat_rate")]
-    pub vat_rate: String,
+    pub vat_category: VatCategory,
+    #[serde(rename = "vat_rate", deserialize_with = "number_from_string")]
+    pub vat_rate: f64,
     pub country: Country,
 }
 
-pub type VatRates = Vec<VatRate>;
+/// VAT rates of a country, one for each category.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct VatRates(pub Vec<VatRate>);
+
+impl VatRates {
+    /// Returns the rate of the given category.
+    ///
+    /// Returns `None` if the country has no rate for the category.
+    pub fn for_category(&self, category: &VatCategory) -> Option<&VatRate> {
+        self.iter().find(|vat_rate| &vat_rate.category == category)
+    }
+}
+
+impl Deref for VatRates {
+    type Target = Vec<VatRate>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoIterator for VatRates {
+    type Item = VatRate;
+    type IntoIter = std::vec::IntoIter<VatRate>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a VatRates {
+    type Item = &'a VatRate;
+    type IntoIter = std::slice::Iter<'a, VatRate>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VatRate {
     #[serde(rename = "country_code")]
     pub country_code: String,
-    pub rate: String,
-    pub category: String,
+    #[serde(deserialize_with = "number_from_string")]
+    pub rate: f64,
+    pub category: VatCategory,
     pub description: String,
 }
+
+/// Category of a purchase, which determines its VAT rate.
+///
+/// Known categories are parsed leniently (e.g. `E-Books` or `ebooks`) and
+/// are sent in their upstream spelling (e.g. `e-books`). Other categories
+/// keep the exact string of the API.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum VatCategory {
+    #[default]
+    Standard,
+    Reduced,
+    Accommodation,
+    Books,
+    Broadcasting,
+    ChildrensClothing,
+    Ebooks,
+    Foodstuffs,
+    Medical,
+    Newspapers,
+    PassengerTransport,
+    Pharmaceuticals,
+    Restaurants,
+    Telecommunications,
+    Water,
+    /// Category that is not known by this library.
+    Other(String),
+}
+
+impl VatCategory {
+    /// Categories that are known by this library.
+    pub const KNOWN: [Self; 15] = [
+        Self::Standard,
+        Self::Reduced,
+        Self::Accommodation,
+        Self::Books,
+        Self::Broadcasting,
+        Self::ChildrensClothing,
+        Self::Ebooks,
+        Self::Foodstuffs,
+        Self::Medical,
+        Self::Newspapers,
+        Self::PassengerTransport,
+        Self::Pharmaceuticals,
+        Self::Restaurants,
+        Self::Telecommunications,
+        Self::Water,
+    ];
+}
+
+impl From<String> for VatCategory {
+    fn from(value: String) -> Self {
+        let compact = value
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_ascii_lowercase();
+        Self::KNOWN
+            .iter()
+            .find(|category| {
+                category
+                    .to_string()
+                    .chars()
+                    .filter(char::is_ascii_alphanumeric)
+                    .eq(compact.chars())
+            })
+            .cloned()
+            .unwrap_or(Self::Other(value))
+    }
+}
+
+impl From<&str> for VatCategory {
+    fn from(value: &str) -> Self {
+        Self::from(value.to_string())
+    }
+}
+
+impl From<VatCategory> for String {
+    fn from(category: VatCategory) -> Self {
+        category.to_string()
+    }
+}
+
+impl fmt::Display for VatCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Standard => "standard",
+                Self::Reduced => "reduced",
+                Self::Accommodation => "accommodation",
+                Self::Books => "books",
+                Self::Broadcasting => "broadcasting",
+                Self::ChildrensClothing => "childrens-clothing",
+                Self::Ebooks => "e-books",
+                Self::Foodstuffs => "foodstuffs",
+                Self::Medical => "medical",
+                Self::Newspapers => "newspapers",
+                Self::PassengerTransport => "passenger-transport",
+                Self::Pharmaceuticals => "pharmaceuticals",
+                Self::Restaurants => "restaurants",
+                Self::Telecommunications => "telecommunications",
+                Self::Water => "water",
+                Self::Other(value) => value,
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_deserialize_vat() -> serde_json::Result<()> {
+        let vat: Vat = serde_json::from_str(
+            r#"{"amount_excluding_vat": "200.00", "amount_including_vat": "238.00",
+            "vat_amount": "38.00", "vat_category": "standard", "vat_rate": "0.190",
+            "country": {"code": "DE", "name": "Germany"}}"#,
+        )?;
+        assert_eq!(238., vat.amount_including_vat);
+        assert_eq!(0.19, vat.vat_rate);
+        assert_eq!(VatCategory::Standard, vat.vat_category);
+        let vat_rates: VatRates = serde_json::from_str(
+            r#"[{"country_code": "DE", "rate": "0.190", "category": "standard", "description": ""},
+            {"country_code": "DE", "rate": "0.070", "category": "e-books", "description": ""}]"#,
+        )?;
+        assert_eq!(
+            Some(0.07),
+            vat_rates
+                .for_category(&VatCategory::Ebooks)
+                .map(|vat_rate| vat_rate.rate)
+        );
+        assert_eq!(None, vat_rates.for_category(&VatCategory::from("wine")));
+        assert_eq!(
+            VatCategory::PassengerTransport,
+            VatCategory::from("Passenger-Transport")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_category_round_trip() -> serde_json::Result<()> {
+        for upstream in [
+            "standard",
+            "e-books",
+            "childrens-clothing",
+            "passenger-transport",
+            "Wine & Spirits",
+        ] {
+            let category: VatCategory = serde_json::from_str(&format!("\"{upstream}\""))?;
+            assert_eq!(upstream, category.to_string());
+            assert_eq!(format!("\"{upstream}\""), serde_json::to_string(&category)?);
+        }
+        assert_eq!(VatCategory::Ebooks, VatCategory::from("ebooks"));
+        assert_eq!("e-books", VatCategory::from("E-Books").to_string());
+        assert_eq!(
+            VatCategory::Other(String::from("Wine & Spirits")),
+            VatCategory::from("Wine & Spirits")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,245 @@
+use super::{is_eu_member_state, Country, Vat, VatCategory, VatDetails};
+use crate::api::Geolocation;
+use serde::{Deserialize, Serialize};
+
+/// Sale of a digital service to a customer who may be in another country.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrossBorderSale {
+    /// ISO 3166-1 alpha-2 code of the seller's country.
+    pub seller_country: String,
+    /// IP address of the customer.
+    pub ip_address: String,
+    /// ISO 3166-1 alpha-2 code of the customer's billing address.
+    pub billing_country: Option<String>,
+    /// VAT number of the customer, for business customers.
+    pub vat_number: Option<String>,
+    /// Price of the sale.
+    pub amount: f64,
+    /// Whether the price includes VAT.
+    pub is_vat_incl: bool,
+    /// Category of the sale, the standard rate applies if it is not set.
+    pub vat_category: Option<VatCategory>,
+}
+
+impl CrossBorderSale {
+    /// Creates a new sale without billing country and VAT number.
+    pub fn new<S: Into<String>>(seller_country: S, ip_address: S, amount: f64) -> Self {
+        Self {
+            seller_country: seller_country.into(),
+            ip_address: ip_address.into(),
+            billing_country: None,
+            vat_number: None,
+            amount,
+            is_vat_incl: false,
+            vat_category: None,
+        }
+    }
+
+    /// Returns the VAT of the sale when it is not charged VAT.
+    pub(crate) fn zero_vat(&self, country_code: &str) -> Vat {
+        Vat {
+            amount_excluding_vat: self.amount,
+            amount_including_vat: self.amount,
+            vat_amount: 0.,
+            vat_category: self.vat_category.clone().unwrap_or_default(),
+            vat_rate: 0.,
+            country: Country {
+                code: country_code.to_string(),
+                name: String::new(),
+            },
+        }
+    }
+}
+
+/// VAT treatment of a sale.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VatTreatment {
+    /// Customer is in the seller's country, domestic VAT is charged.
+    #[default]
+    Domestic,
+    /// Consumer in another EU member state, VAT of the customer's country is charged.
+    CustomerCountry,
+    /// Business in another EU member state, the customer accounts for the VAT.
+    ReverseCharge,
+    /// Customer is outside the EU, no EU VAT is charged.
+    OutsideScope,
+    /// Customer's country is unknown, the sale needs a manual review.
+    InsufficientEvidence,
+}
+
+/// Evidence that is used for determining the customer's country.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VatEvidence {
+    /// IP address of the customer.
+    pub ip_address: String,
+    /// Country of the IP address.
+    pub ip_country: String,
+    /// Whether the IP address belongs to a VPN, which makes it unreliable.
+    pub is_vpn: bool,
+    /// Country of the billing address.
+    pub billing_country: Option<String>,
+    /// Normalized VAT number of the customer.
+    pub vat_number: Option<String>,
+    /// Whether the VAT number is valid.
+    pub vat_number_valid: bool,
+    /// Country of the VAT number.
+    pub vat_number_country: Option<String>,
+    /// Company name that is registered for the VAT number.
+    pub company_name: Option<String>,
+    /// Whether the IP address and the billing address are in different countries.
+    pub is_conflicting: bool,
+}
+
+impl VatEvidence {
+    /// Collects the evidence from the API results.
+    ///
+    /// `vat_number` is the number as entered, `vat_details` is `None` if it was
+    /// not entered or rejected by the local validation.
+    pub(crate) fn new(
+        geolocation: &Geolocation,
+        billing_country: Option<&str>,
+        vat_number: Option<&str>,
+        vat_details: Option<&VatDetails>,
+    ) -> Self {
+        let ip_country = geolocation.country_code.to_ascii_uppercase();
+        let billing_country = billing_country.map(str::to_ascii_uppercase);
+        let vat_number_valid = vat_details.is_some_and(|vat_details| vat_details.valid);
+        Self {
+            ip_address: geolocation.ip_address.to_string(),
+            is_conflicting: billing_country
+                .as_ref()
+                .is_some_and(|billing_country| billing_country != &ip_country),
+            ip_country,
+            is_vpn: geolocation.security.is_vpn,
+            billing_country,
+            vat_number: vat_details
+                .map(|vat_details| vat_details.vat_number.to_string())
+                .or_else(|| vat_number.map(String::from)),
+            vat_number_valid,
+            vat_number_country: vat_details
+                .filter(|_| vat_number_valid)
+                .map(|vat_details| iso_country_code(&vat_details.country.code)),
+            company_name: vat_details
+                .filter(|_| vat_number_valid)
+                .map(|vat_details| vat_details.company.name.to_string()),
+        }
+    }
+
+    /// Returns the customer's country.
+    ///
+    /// The country of a valid VAT number takes precedence, then the billing
+    /// address and then the IP address. Returns `None` if none of them is a
+    /// country code (e.g. the IP address could not be located).
+    pub fn customer_country(&self) -> Option<&str> {
+        [
+            self.vat_number_country.as_deref(),
+            self.billing_country.as_deref(),
+            Some(self.ip_country.as_str()),
+        ]
+        .iter()
+        .copied()
+        .flatten()
+        .find(|country| country.len() == 2 && country.chars().all(|c| c.is_ascii_alphabetic()))
+    }
+
+    /// Returns the VAT treatment of a sale from the given country.
+    pub fn treatment<S: AsRef<str>>(&self, seller_country: S) -> VatTreatment {
+        let customer_country = match self.customer_country() {
+            Some(customer_country) => customer_country,
+            None => return VatTreatment::InsufficientEvidence,
+        };
+        if !is_eu_member_state(customer_country) {
+            VatTreatment::OutsideScope
+        } else if customer_country.eq_ignore_ascii_case(seller_country.as_ref()) {
+            VatTreatment::Domestic
+        } else if self.vat_number_valid {
+            VatTreatment::ReverseCharge
+        } else {
+            VatTreatment::CustomerCountry
+        }
+    }
+}
+
+/// Result of a cross-border VAT determination.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VatDetermination {
+    /// Country whose VAT rules apply, empty if the evidence is insufficient.
+    pub taxable_country: String,
+    /// VAT treatment of the sale.
+    pub treatment: VatTreatment,
+    /// VAT of the sale, zero for reverse charge and sales outside the EU.
+    ///
+    /// `None` if the evidence is insufficient, in which case the sale should be
+    /// refused or reviewed.
+    pub vat: Option<Vat>,
+    /// Evidence that the determination is based on.
+    pub evidence: VatEvidence,
+}
+
+/// Converts the `EL` VAT prefix of Greece into its ISO 3166-1 code.
+fn iso_country_code(country_code: &str) -> String {
+    match country_code.to_ascii_uppercase().as_str() {
+        "EL" => String::from("GR"),
+        country_code => country_code.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Security;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_treatment() {
+        let geolocation = Geolocation {
+            ip_address: String::from("1.2.3.4"),
+            country_code: String::from("FR"),
+            ..Geolocation::default()
+        };
+        let evidence = VatEvidence::new(&geolocation, None, None, None);
+        assert_eq!(Some("FR"), evidence.customer_country());
+        assert_eq!(VatTreatment::CustomerCountry, evidence.treatment("DE"));
+        assert_eq!(VatTreatment::Domestic, evidence.treatment("fr"));
+
+        let evidence = VatEvidence::new(&geolocation, Some("us"), None, None);
+        assert!(evidence.is_conflicting);
+        assert_eq!(VatTreatment::OutsideScope, evidence.treatment("DE"));
+
+        let vat_details = VatDetails {
+            vat_number: String::from("EL094259216"),
+            valid: true,
+            country: Country {
+                code: String::from("EL"),
+                name: String::from("Greece"),
+            },
+            ..VatDetails::default()
+        };
+        let evidence = VatEvidence::new(
+            &Geolocation {
+                security: Security { is_vpn: true },
+                ..geolocation
+            },
+            Some("FR"),
+            Some("EL 094259216"),
+            Some(&vat_details),
+        );
+        assert_eq!(Some("EL094259216"), evidence.vat_number.as_deref());
+        assert_eq!(Some("GR"), evidence.customer_country());
+        assert_eq!(VatTreatment::ReverseCharge, evidence.treatment("DE"));
+        assert_eq!(VatTreatment::Domestic, evidence.treatment("GR"));
+
+        let unlocated = Geolocation {
+            ip_address: String::from("10.0.0.1"),
+            ..Geolocation::default()
+        };
+        let evidence = VatEvidence::new(&unlocated, None, None, None);
+        assert_eq!(None, evidence.customer_country());
+        assert_eq!(VatTreatment::InsufficientEvidence, evidence.treatment("DE"));
+        let evidence = VatEvidence::new(&unlocated, Some(""), None, None);
+        assert_eq!(VatTreatment::InsufficientEvidence, evidence.treatment("DE"));
+        let evidence = VatEvidence::new(&unlocated, Some("at"), None, None);
+        assert_eq!(VatTreatment::CustomerCountry, evidence.treatment("DE"));
+    }
+}
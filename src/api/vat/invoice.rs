@@ -0,0 +1,280 @@
+use super::{Vat, VatCategory, VatTable};
+use crate::error::Result;
+use crate::rounding::RoundingMode;
+use serde::{Deserialize, Serialize};
+
+/// Line item of an invoice.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LineItem {
+    /// Description of the item.
+    pub description: String,
+    /// Unit price of the item.
+    pub amount: f64,
+    /// Quantity of the item.
+    pub quantity: f64,
+    /// Category of the item, the standard rate applies if it is not set.
+    pub category: Option<VatCategory>,
+    /// Whether the unit price includes VAT (gross) or not (net).
+    pub is_vat_incl: bool,
+}
+
+impl Default for LineItem {
+    fn default() -> Self {
+        Self {
+            description: String::new(),
+            amount: 0.,
+            quantity: 1.,
+            category: None,
+            is_vat_incl: false,
+        }
+    }
+}
+
+/// Invoice with line items that are taxed in a single country.
+///
+/// VAT is computed and rounded for each line, and the subtotals and totals
+/// are the sums of the rounded lines, so they always add up.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Invoice {
+    country_code: String,
+    lines: Vec<LineItem>,
+}
+
+/// VAT of an invoice line.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvoiceLineVat {
+    /// Description of the item.
+    pub description: String,
+    /// Quantity of the item.
+    pub quantity: f64,
+    /// Category of the applied rate.
+    pub vat_category: VatCategory,
+    /// Applied VAT rate.
+    pub vat_rate: f64,
+    /// Line total without VAT.
+    pub amount_excluding_vat: f64,
+    /// VAT of the line.
+    pub vat_amount: f64,
+    /// Line total with VAT.
+    pub amount_including_vat: f64,
+}
+
+/// Sum of the invoice lines that have the same VAT rate.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VatSubtotal {
+    /// VAT rate of the lines.
+    pub vat_rate: f64,
+    /// Total without VAT.
+    pub amount_excluding_vat: f64,
+    /// Total VAT.
+    pub vat_amount: f64,
+    /// Total with VAT.
+    pub amount_including_vat: f64,
+}
+
+/// VAT of an invoice with per-line amounts, per-rate subtotals and totals.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvoiceVat {
+    /// ISO 3166-1 alpha-2 code of the country.
+    pub country_code: String,
+    /// VAT of each line, in the order of the invoice.
+    pub lines: Vec<InvoiceLineVat>,
+    /// Subtotals for each VAT rate, in ascending order of the rate.
+    pub subtotals: Vec<VatSubtotal>,
+    /// Total without VAT.
+    pub total_excluding_vat: f64,
+    /// Total VAT.
+    pub total_vat: f64,
+    /// Total with VAT.
+    pub total_including_vat: f64,
+}
+
+impl Invoice {
+    /// Creates a new invoice for the given country.
+    pub fn new<S: AsRef<str>>(country_code: S) -> Self {
+        Self {
+            country_code: country_code.as_ref().to_ascii_uppercase(),
+            lines: Vec::new(),
+        }
+    }
+
+    /// Adds a line item to the invoice.
+    pub fn add_line(&mut self, line: LineItem) -> &mut Self {
+        self.lines.push(line);
+        self
+    }
+
+    /// Returns the country of the invoice.
+    pub fn country_code(&self) -> &str {
+        &self.country_code
+    }
+
+    /// Returns the line items of the invoice.
+    pub fn lines(&self) -> &[LineItem] {
+        &self.lines
+    }
+
+    /// Computes the VAT of the invoice locally with the rates of the given table.
+    pub fn calculate(&self, vat_table: &VatTable) -> Result<InvoiceVat> {
+        self.build(
+            vat_table.rounding_mode(),
+            |amount, is_vat_incl, category| {
+                vat_table.calculate(amount, &self.country_code, is_vat_incl, category)
+            },
+        )
+    }
+
+    /// Computes the VAT of the invoice with the given VAT calculator for each line.
+    ///
+    /// The calculator is called with the line total, the gross flag and the category.
+    /// The line totals and sums are rounded to cents with the given rounding mode,
+    /// which removes the floating point errors of the sums.
+    pub(crate) fn build<F>(
+        &self,
+        rounding_mode: RoundingMode,
+        mut calculate: F,
+    ) -> Result<InvoiceVat>
+    where
+        F: FnMut(f64, bool, Option<VatCategory>) -> Result<Vat>,
+    {
+        let round_cents = |amount: f64| rounding_mode.round(amount, 2);
+        let mut invoice_vat = InvoiceVat {
+            country_code: self.country_code.to_string(),
+            ..InvoiceVat::default()
+        };
+        for line in &self.lines {
+            let vat = calculate(
+                round_cents(line.amount * line.quantity),
+                line.is_vat_incl,
+                line.category.clone(),
+            )?;
+            let subtotal = match invoice_vat
+                .subtotals
+                .iter_mut()
+                .find(|subtotal| subtotal.vat_rate == vat.vat_rate)
+            {
+                Some(subtotal) => subtotal,
+                None => {
+                    invoice_vat.subtotals.push(VatSubtotal {
+                        vat_rate: vat.vat_rate,
+                        ..VatSubtotal::default()
+                    });
+                    invoice_vat.subtotals.last_mut().expect("subtotal")
+                }
+            };
+            subtotal.amount_excluding_vat =
+                round_cents(subtotal.amount_excluding_vat + vat.amount_excluding_vat);
+            subtotal.vat_amount = round_cents(subtotal.vat_amount + vat.vat_amount);
+            subtotal.amount_including_vat =
+                round_cents(subtotal.amount_including_vat + vat.amount_including_vat);
+            invoice_vat.total_excluding_vat =
+                round_cents(invoice_vat.total_excluding_vat + vat.amount_excluding_vat);
+            invoice_vat.total_vat = round_cents(invoice_vat.total_vat + vat.vat_amount);
+            invoice_vat.total_including_vat =
+                round_cents(invoice_vat.total_including_vat + vat.amount_including_vat);
+            invoice_vat.lines.push(InvoiceLineVat {
+                description: line.description.to_string(),
+                quantity: line.quantity,
+                vat_category: vat.vat_category,
+                vat_rate: vat.vat_rate,
+                amount_excluding_vat: vat.amount_excluding_vat,
+                vat_amount: vat.vat_amount,
+                amount_including_vat: vat.amount_including_vat,
+            });
+        }
+        invoice_vat
+            .subtotals
+            .sort_by(|a, b| a.vat_rate.total_cmp(&b.vat_rate));
+        Ok(invoice_vat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{VatRate, VatRates};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_calculate() -> Result<()> {
+        let vat_rate = |rate, category: &str| VatRate {
+            country_code: String::from("DE"),
+            rate,
+            category: VatCategory::from(category),
+            description: String::new(),
+        };
+        let mut vat_table = VatTable::new();
+        vat_table.insert(
+            "DE",
+            VatRates(vec![vat_rate(0.19, "standard"), vat_rate(0.07, "books")]),
+        );
+        let mut invoice = Invoice::new("de");
+        invoice
+            .add_line(LineItem {
+                description: String::from("Book"),
+                amount: 10.,
+                quantity: 3.,
+                category: Some(VatCategory::Books),
+                is_vat_incl: true,
+            })
+            .add_line(LineItem {
+                description: String::from("Headphones"),
+                amount: 49.99,
+                ..LineItem::default()
+            })
+            .add_line(LineItem {
+                description: String::from("Cable"),
+                amount: 0.1,
+                quantity: 7.,
+                ..LineItem::default()
+            });
+        let invoice_vat = invoice.calculate(&vat_table)?;
+        assert_eq!(3, invoice_vat.lines.len());
+        assert_eq!(1.96, invoice_vat.lines[0].vat_amount);
+        assert_eq!(9.5, invoice_vat.lines[1].vat_amount);
+        assert_eq!(
+            vec![
+                VatSubtotal {
+                    vat_rate: 0.07,
+                    amount_excluding_vat: 28.04,
+                    vat_amount: 1.96,
+                    amount_including_vat: 30.,
+                },
+                VatSubtotal {
+                    vat_rate: 0.19,
+                    amount_excluding_vat: 50.69,
+                    vat_amount: 9.63,
+                    amount_including_vat: 60.32,
+                },
+            ],
+            invoice_vat.subtotals
+        );
+        assert_eq!(
+            (78.73, 11.59, 90.32),
+            (
+                invoice_vat.total_excluding_vat,
+                invoice_vat.total_vat,
+                invoice_vat.total_including_vat
+            )
+        );
+
+        vat_table.set_rounding_mode(RoundingMode::Down);
+        let mut invoice = Invoice::new("de");
+        invoice.add_line(LineItem {
+            description: String::from("Service"),
+            amount: 10.555,
+            quantity: 1.,
+            ..LineItem::default()
+        });
+        let invoice_vat = invoice.calculate(&vat_table)?;
+        assert_eq!(
+            (10.55, 2.),
+            (
+                invoice_vat.lines[0].amount_excluding_vat,
+                invoice_vat.lines[0].vat_amount
+            )
+        );
+        assert_eq!(10.55, invoice_vat.subtotals[0].amount_excluding_vat);
+        Ok(())
+    }
+}
@@ -0,0 +1,459 @@
+use crate::error::{Error, Result};
+
+/// ISO 3166-1 alpha-2 codes of the EU member states.
+pub const EU_MEMBER_STATES: [&str; 27] = [
+    "AT", "BE", "BG", "CY", "CZ", "DE", "DK", "EE", "ES", "FI", "FR", "GR", "HR", "HU", "IE", "IT",
+    "LT", "LU", "LV", "MT", "NL", "PL", "PT", "RO", "SE", "SI", "SK",
+];
+
+/// Returns whether the country (ISO 3166-1 alpha-2 code or VAT prefix) is an EU member state.
+pub fn is_eu_member_state<S: AsRef<str>>(country_code: S) -> bool {
+    let country_code = country_code.as_ref().to_ascii_uppercase();
+    country_code == "EL" || EU_MEMBER_STATES.contains(&country_code.as_str())
+}
+
+/// Normalizes a VAT number and validates it locally.
+///
+/// Spaces, dashes, dots and slashes are removed, the number is uppercased and
+/// the `GR` prefix is replaced with `EL` (e.g. `se 5566-5668 8001` becomes
+/// `SE556656688001`). For EU member states, the national format and check digits
+/// are validated. Numbers of other countries are only normalized.
+pub fn normalize_vat_number<S: AsRef<str>>(vat_number: S) -> Result<String> {
+    let vat_number = vat_number.as_ref();
+    let normalized = vat_number
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '.' | '/'))
+        .collect::<String>()
+        .to_ascii_uppercase();
+    let is_valid = normalized.len() > 2
+        && normalized.chars().all(|c| c.is_ascii_alphanumeric())
+        && normalized.chars().take(2).all(|c| c.is_ascii_alphabetic());
+    if !is_valid {
+        return Err(Error::InvalidVatNumber(vat_number.to_string()));
+    }
+    let (prefix, number) = normalized.split_at(2);
+    let prefix = if prefix == "GR" { "EL" } else { prefix };
+    let number = match (prefix, number.len()) {
+        // Belgian numbers had 9 digits before 2005.
+        ("BE", 9) => format!("0{number}"),
+        _ => number.to_string(),
+    };
+    if is_eu_member_state(prefix) && !is_valid_number(prefix, &number) {
+        return Err(Error::InvalidVatNumber(vat_number.to_string()));
+    }
+    Ok(format!("{prefix}{number}"))
+}
+
+/// Returns whether the number (without the prefix) is valid in the given member state.
+fn is_valid_number(prefix: &str, number: &str) -> bool {
+    let digits = number
+        .chars()
+        .map(|c| c.to_digit(10))
+        .collect::<Option<Vec<u32>>>();
+    let is_numeric = |lengths: &[usize]| {
+        digits
+            .as_ref()
+            .is_some_and(|digits| lengths.contains(&digits.len()))
+    };
+    match prefix {
+        "AT" => match number.strip_prefix('U') {
+            Some(number) if number.len() == 8 => to_digits(number).is_some_and(|d| {
+                let sum = d[..7]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &d)| if i % 2 == 0 { d } else { digit_sum(d * 2) })
+                    .sum::<u32>();
+                (96 - sum) % 10 == d[7]
+            }),
+            _ => false,
+        },
+        "BE" => {
+            is_numeric(&[10])
+                && number.as_bytes()[0] <= b'1'
+                && number[..8]
+                    .parse::<u32>()
+                    .is_ok_and(|n| number[8..].parse::<u32>() == Ok(97 - n % 97))
+        }
+        "BG" => {
+            let d = match digits {
+                Some(d) if d.len() == 9 || d.len() == 10 => d,
+                _ => return false,
+            };
+            if d.len() == 9 {
+                let check = weighted_sum(&d[..8], &[1, 2, 3, 4, 5, 6, 7, 8]) % 11;
+                let check = if check == 10 {
+                    weighted_sum(&d[..8], &[3, 4, 5, 6, 7, 8, 9, 10]) % 11 % 10
+                } else {
+                    check
+                };
+                check == d[8]
+            } else {
+                // Personal numbers, foreigners and other entities use different weights.
+                let person = weighted_sum(&d[..9], &[2, 4, 8, 5, 10, 9, 7, 3, 6]) % 11 % 10;
+                let foreigner = weighted_sum(&d[..9], &[21, 19, 17, 13, 11, 9, 7, 3, 1]) % 10;
+                let other = (11 - weighted_sum(&d[..9], &[4, 3, 2, 7, 6, 5, 4, 3, 2]) % 11) % 11;
+                [person, foreigner, other].contains(&d[9])
+            }
+        }
+        "CY" => {
+            let (d, letter) = number.split_at(number.len().saturating_sub(1));
+            match (to_digits(d), letter.chars().next()) {
+                (Some(d), Some(letter)) if d.len() == 8 && d[0] != 2 => {
+                    const ODD: [u32; 10] = [1, 0, 5, 7, 9, 13, 15, 17, 19, 21];
+                    let sum = d
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &d)| if i % 2 == 0 { ODD[d as usize] } else { d })
+                        .sum::<u32>();
+                    letter == (b'A' + (sum % 26) as u8) as char
+                }
+                _ => false,
+            }
+        }
+        "CZ" => match digits {
+            Some(d) if d.len() == 8 => {
+                let check = (11 - weighted_sum(&d[..7], &[8, 7, 6, 5, 4, 3, 2]) % 11) % 11;
+                d[0] != 9 && (if check == 0 { 1 } else { check }) % 10 == d[7]
+            }
+            // Personal numbers are only checked for their format.
+            Some(d) => d.len() == 9 || d.len() == 10,
+            None => false,
+        },
+        "DE" => is_numeric(&[9]) && digits.is_some_and(|d| d[0] != 0 && iso7064_mod_11_10(&d)),
+        "DK" => {
+            is_numeric(&[8])
+                && digits.is_some_and(|d| {
+                    d[0] != 0 && weighted_sum(&d, &[2, 7, 6, 5, 4, 3, 2, 1]).is_multiple_of(11)
+                })
+        }
+        "EE" => {
+            is_numeric(&[9])
+                && digits.is_some_and(|d| {
+                    (10 - weighted_sum(&d[..8], &[3, 7, 1, 3, 7, 1, 3, 7]) % 10) % 10 == d[8]
+                })
+        }
+        "EL" => {
+            is_numeric(&[9])
+                && digits.is_some_and(|d| {
+                    weighted_sum(&d[..8], &[256, 128, 64, 32, 16, 8, 4, 2]) % 11 % 10 == d[8]
+                })
+        }
+        "ES" => is_valid_spanish_number(number),
+        "FI" => {
+            is_numeric(&[8])
+                && digits.is_some_and(|d| {
+                    let check = 11 - weighted_sum(&d[..7], &[7, 9, 10, 5, 8, 4, 2]) % 11;
+                    check % 11 == d[7] && check != 10
+                })
+        }
+        "FR" => {
+            let (key, siren) = number.split_at(number.len().min(2));
+            let is_valid_key = key.len() == 2
+                && key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() && c != 'I' && c != 'O');
+            match (is_valid_key, siren.parse::<u64>(), key.parse::<u64>()) {
+                (true, Ok(n), Ok(key)) if siren.len() == 9 => (12 + 3 * (n % 97)) % 97 == key,
+                // Keys with letters are only checked for their format.
+                (true, Ok(_), Err(_)) => siren.len() == 9,
+                _ => false,
+            }
+        }
+        "HR" => is_numeric(&[11]) && digits.is_some_and(|d| iso7064_mod_11_10(&d)),
+        "HU" => {
+            is_numeric(&[8])
+                && digits.is_some_and(|d| {
+                    (10 - weighted_sum(&d[..7], &[9, 7, 3, 1, 9, 7, 3]) % 10) % 10 == d[7]
+                })
+        }
+        "IE" => is_valid_irish_number(number),
+        "IT" => {
+            is_numeric(&[11])
+                && digits.is_some_and(|d| {
+                    let office = d[7] * 100 + d[8] * 10 + d[9];
+                    luhn(&d)
+                        && ((1..=100).contains(&office) || [120, 121, 888, 999].contains(&office))
+                })
+        }
+        "LT" => {
+            is_numeric(&[9, 12])
+                && digits.is_some_and(|d| {
+                    let n = d.len() - 1;
+                    d[n - 1] == 1 && {
+                        let sum = |offset| {
+                            d[..n]
+                                .iter()
+                                .enumerate()
+                                .map(|(i, &d)| d * (1 + (i as u32 + offset) % 9))
+                                .sum::<u32>()
+                                % 11
+                        };
+                        let check = match sum(0) {
+                            10 => sum(2) % 10,
+                            check => check,
+                        };
+                        check == d[n]
+                    }
+                })
+        }
+        "LU" => {
+            is_numeric(&[8])
+                && number[..6]
+                    .parse::<u32>()
+                    .is_ok_and(|n| number[6..].parse::<u32>() == Ok(n % 89))
+        }
+        "LV" => match digits {
+            // Legal entities.
+            Some(d) if d.len() == 11 && d[0] > 3 => {
+                weighted_sum(&d, &[9, 1, 4, 8, 3, 10, 2, 5, 7, 6, 1]) % 11 == 3
+            }
+            // Personal numbers are only checked for their format.
+            Some(d) => d.len() == 11,
+            None => false,
+        },
+        "MT" => {
+            is_numeric(&[8])
+                && digits.is_some_and(|d| {
+                    let check = d[6] * 10 + d[7];
+                    d[0] != 0
+                        && (weighted_sum(&d[..6], &[3, 4, 6, 7, 8, 9]) + check).is_multiple_of(37)
+                })
+        }
+        "NL" => is_valid_dutch_number(number),
+        "PL" => {
+            is_numeric(&[10])
+                && digits.is_some_and(|d| {
+                    weighted_sum(&d[..9], &[6, 5, 7, 2, 3, 4, 5, 6, 7]) % 11 == d[9]
+                })
+        }
+        "PT" => {
+            is_numeric(&[9])
+                && digits.is_some_and(|d| {
+                    let check = 11 - weighted_sum(&d[..8], &[9, 8, 7, 6, 5, 4, 3, 2]) % 11;
+                    d[0] != 0 && (if check >= 10 { 0 } else { check }) == d[8]
+                })
+        }
+        "RO" => match digits {
+            Some(d) if (2..=10).contains(&d.len()) && d[0] != 0 => {
+                let n = d.len() - 1;
+                let weights = &[7, 5, 3, 2, 1, 7, 5, 3, 2][9 - n..];
+                weighted_sum(&d[..n], weights) * 10 % 11 % 10 == d[n]
+            }
+            _ => false,
+        },
+        "SE" => {
+            is_numeric(&[12]) && number.ends_with("01") && digits.is_some_and(|d| luhn(&d[..10]))
+        }
+        "SI" => {
+            is_numeric(&[8])
+                && digits.is_some_and(|d| {
+                    let check = 11 - weighted_sum(&d[..7], &[8, 7, 6, 5, 4, 3, 2]) % 11;
+                    d[0] != 0 && check != 11 && check % 10 == d[7]
+                })
+        }
+        "SK" => {
+            is_numeric(&[10])
+                && digits.is_some_and(|d| {
+                    d[0] != 0
+                        && [2, 3, 4, 7, 8, 9].contains(&d[2])
+                        && number.parse::<u64>().is_ok_and(|n| n % 11 == 0)
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Validates a Spanish NIF (natural persons) or CIF (legal entities).
+fn is_valid_spanish_number(number: &str) -> bool {
+    let chars = number.chars().collect::<Vec<char>>();
+    if chars.len() != 9 {
+        return false;
+    }
+    let (first, last) = (chars[0], chars[8]);
+    let middle = match to_digits(&number[1..8]) {
+        Some(middle) => middle,
+        None => return false,
+    };
+    if first.is_ascii_digit() || "KLMXYZ".contains(first) {
+        // DNI and NIE numbers end with a control letter.
+        let prefix = match first {
+            'X' => "0",
+            'Y' => "1",
+            'Z' => "2",
+            'K' | 'L' | 'M' => "",
+            _ => &number[..1],
+        };
+        format!("{prefix}{}", &number[1..8])
+            .parse::<usize>()
+            .is_ok_and(|n| "TRWAGMYFPDXBNJZSQVHLCKE".as_bytes()[n % 23] as char == last)
+    } else if "ABCDEFGHJNPQRSUVW".contains(first) {
+        let sum = middle
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| if i % 2 == 0 { digit_sum(d * 2) } else { d })
+            .sum::<u32>();
+        let check = (10 - sum % 10) % 10;
+        last.to_digit(10) == Some(check) || "JABCDEFGHI".as_bytes()[check as usize] as char == last
+    } else {
+        false
+    }
+}
+
+/// Validates an Irish number in the current or the old (pre-2013) format.
+fn is_valid_irish_number(number: &str) -> bool {
+    let chars = number.chars().collect::<Vec<char>>();
+    let number = match chars.as_slice() {
+        // Old format, e.g. `8Z49289F`.
+        [first, second, ..]
+            if chars.len() == 8
+                && first.is_ascii_digit()
+                && "+*ABCDEFGHIJKLMNOPQRSTUVWXYZ".contains(*second) =>
+        {
+            format!("0{}{first}{}", &number[2..7], &number[7..])
+        }
+        _ => number.to_string(),
+    };
+    let (digits, letters) = number.split_at(number.len().min(7));
+    let digits = match to_digits(digits) {
+        Some(digits) if digits.len() == 7 => digits,
+        _ => return false,
+    };
+    let letters = letters.as_bytes();
+    if letters.is_empty() || letters.len() > 2 || !letters.iter().all(u8::is_ascii_uppercase) {
+        return false;
+    }
+    let mut sum = weighted_sum(&digits, &[8, 7, 6, 5, 4, 3, 2]);
+    if let Some(&second) = letters.get(1) {
+        if second > b'I' && second != b'W' {
+            return false;
+        }
+        if second != b'W' {
+            sum += u32::from(second - b'A' + 1) * 9;
+        }
+    }
+    b"WABCDEFGHIJKLMNOPQRSTUV"[(sum % 23) as usize] == letters[0]
+}
+
+/// Validates a Dutch number with the old (mod 11) or the new (mod 97) check.
+fn is_valid_dutch_number(number: &str) -> bool {
+    let (digits, suffix) = number.split_at(number.len().min(9));
+    let digits = match to_digits(digits) {
+        Some(digits) if digits.len() == 9 => digits,
+        _ => return false,
+    };
+    let is_valid_suffix = suffix.len() == 3
+        && suffix.starts_with('B')
+        && suffix[1..].chars().all(|c| c.is_ascii_digit());
+    if !is_valid_suffix {
+        return false;
+    }
+    let mod_11 = weighted_sum(&digits[..8], &[9, 8, 7, 6, 5, 4, 3, 2]) % 11 == digits[8];
+    let mod_97 = format!("NL{number}")
+        .chars()
+        .filter_map(|c| c.to_digit(36))
+        .fold(0, |remainder, value| {
+            let factor = if value < 10 { 10 } else { 100 };
+            (remainder * factor + value) % 97
+        })
+        == 1;
+    mod_11 || mod_97
+}
+
+/// Converts a string of ASCII digits into digits.
+fn to_digits(value: &str) -> Option<Vec<u32>> {
+    value.chars().map(|c| c.to_digit(10)).collect()
+}
+
+/// Returns the sum of the digits multiplied with the weights.
+fn weighted_sum(digits: &[u32], weights: &[u32]) -> u32 {
+    digits.iter().zip(weights).map(|(d, w)| d * w).sum()
+}
+
+/// Returns the sum of the decimal digits of a number.
+fn digit_sum(value: u32) -> u32 {
+    value / 10 + value % 10
+}
+
+/// Validates the digits with the Luhn algorithm.
+fn luhn(digits: &[u32]) -> bool {
+    digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| if i % 2 == 1 { digit_sum(d * 2) } else { d })
+        .sum::<u32>()
+        % 10
+        == 0
+}
+
+/// Validates the digits with the ISO 7064 mod 11,10 algorithm.
+fn iso7064_mod_11_10(digits: &[u32]) -> bool {
+    let (check, digits) = match digits.split_last() {
+        Some(split) => split,
+        None => return false,
+    };
+    let product = digits.iter().fold(10, |product, &d| {
+        let sum = (product + d) % 10;
+        (if sum == 0 { 10 } else { sum }) * 2 % 11
+    });
+    (11 - product) % 10 == *check
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_normalize_vat_number() -> Result<()> {
+        assert_eq!("SE556656688001", normalize_vat_number("se 5566-5668 8001")?);
+        assert_eq!("EL094259216", normalize_vat_number("GR 094259216")?);
+        assert_eq!("BE0776091951", normalize_vat_number("BE776091951")?);
+        assert_eq!("GB980780684", normalize_vat_number("GB 980 7806 84")?);
+        for vat_number in [
+            "ATU13585627",
+            "BE0403019261",
+            "BG175074752",
+            "CY10259033P",
+            "CZ25123891",
+            "DE136695976",
+            "DK13585628",
+            "EE100931558",
+            "ESA13585625",
+            "ES54362315K",
+            "FI20774740",
+            "FR40303265045",
+            "HR33392005961",
+            "HU12892312",
+            "IE6433435F",
+            "IE8Z49289F",
+            "IT00743110157",
+            "LT119511515",
+            "LU15027442",
+            "LV40003521600",
+            "MT11679112",
+            "NL004495445B01",
+            "PL8567346215",
+            "PT501964843",
+            "RO18547290",
+            "SI50223054",
+            "SK2022749619",
+        ] {
+            assert_eq!(vat_number, normalize_vat_number(vat_number)?);
+        }
+        for vat_number in [
+            "",
+            "SE",
+            "556656688001",
+            "SE556656688002",
+            "DE136695977",
+            "ATU13585626",
+            "IT00743110158",
+            "NL004495446B01",
+            "SE 5566#5668",
+        ] {
+            assert!(normalize_vat_number(vat_number).is_err(), "{}", vat_number);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+use super::{Country, Vat, VatCategory, VatRate, VatRates};
+use crate::error::{Error, Result};
+use crate::rounding::RoundingMode;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default interval after which the rates of a country are considered stale.
+const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Snapshot of the VAT rates of a country.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct CountryRates {
+    rates: VatRates,
+    fetched_at: u64,
+}
+
+/// Offline VAT calculator built from the VAT rates of multiple countries.
+///
+/// The table can be saved as JSON and loaded again, so the rates only need to be
+/// fetched when they are older than the refresh interval (see [`VatTable::is_stale`]).
+/// Amounts are rounded to cents.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VatTable {
+    countries: BTreeMap<String, CountryRates>,
+    #[serde(default)]
+    rounding_mode: RoundingMode,
+    #[serde(default = "default_refresh_interval")]
+    refresh_interval: Duration,
+}
+
+impl Default for VatTable {
+    fn default() -> Self {
+        Self {
+            countries: BTreeMap::new(),
+            rounding_mode: RoundingMode::default(),
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+        }
+    }
+}
+
+/// Returns the default refresh interval for deserialization.
+fn default_refresh_interval() -> Duration {
+    DEFAULT_REFRESH_INTERVAL
+}
+
+impl VatTable {
+    /// Creates a new empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a table from the given JSON file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents).map_err(std::io::Error::from)?)
+    }
+
+    /// Saves the table into the given JSON file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let contents = serde_json::to_string_pretty(self).map_err(std::io::Error::from)?;
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Returns the rounding mode that is applied to the computed amounts.
+    pub fn rounding_mode(&self) -> RoundingMode {
+        self.rounding_mode
+    }
+
+    /// Sets the rounding mode that is applied to the computed amounts.
+    pub fn set_rounding_mode(&mut self, rounding_mode: RoundingMode) {
+        self.rounding_mode = rounding_mode;
+    }
+
+    /// Sets the interval after which the rates of a country are considered stale.
+    ///
+    /// Defaults to a day.
+    pub fn set_refresh_interval(&mut self, refresh_interval: Duration) {
+        self.refresh_interval = refresh_interval;
+    }
+
+    /// Inserts (or replaces) the VAT rates of a country that are fetched now.
+    pub fn insert<S: AsRef<str>>(&mut self, country_code: S, rates: VatRates) {
+        let fetched_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.countries.insert(
+            country_code.as_ref().to_ascii_uppercase(),
+            CountryRates { rates, fetched_at },
+        );
+    }
+
+    /// Returns the country codes in the table.
+    pub fn countries(&self) -> impl Iterator<Item = &str> {
+        self.countries.keys().map(String::as_str)
+    }
+
+    /// Returns the VAT rates of a country.
+    pub fn rates<S: AsRef<str>>(&self, country_code: S) -> Option<&VatRates> {
+        self.countries
+            .get(&country_code.as_ref().to_ascii_uppercase())
+            .map(|country| &country.rates)
+    }
+
+    /// Returns whether the rates of any country are older than the refresh interval.
+    pub fn is_stale(&self) -> bool {
+        !self.stale_countries().is_empty()
+    }
+
+    /// Returns the countries with rates that are older than the refresh interval.
+    pub fn stale_countries(&self) -> Vec<String> {
+        self.stale_countries_at(SystemTime::now())
+    }
+
+    /// Returns the countries with rates that are stale at the given point in time.
+    pub fn stale_countries_at(&self, now: SystemTime) -> Vec<String> {
+        self.countries
+            .iter()
+            .filter(|(_, country)| {
+                let fetched_at = UNIX_EPOCH + Duration::from_secs(country.fetched_at);
+                now.duration_since(fetched_at).unwrap_or_default() >= self.refresh_interval
+            })
+            .map(|(country_code, _)| country_code.to_string())
+            .collect()
+    }
+
+    /// Computes the VAT of an amount locally, like [`AbstractApi::calculate_vat`].
+    ///
+    /// The standard rate is used if no category is given. Fails if the country
+    /// has no rate for the category. The name of the returned country is empty.
+    ///
+    /// [`AbstractApi::calculate_vat`]: crate::AbstractApi::calculate_vat
+    pub fn calculate<S: AsRef<str>>(
+        &self,
+        amount: f64,
+        country_code: S,
+        is_vat_incl: bool,
+        vat_category: Option<VatCategory>,
+    ) -> Result<Vat> {
+        let country_code = country_code.as_ref().to_ascii_uppercase();
+        let vat_category = vat_category.unwrap_or_default();
+        let vat_rate = self.rate(&country_code, &vat_category)?;
+        let amount = self.rounding_mode.round(amount, 2);
+        let (amount_excluding_vat, vat_amount) = if is_vat_incl {
+            let vat_amount = self
+                .rounding_mode
+                .round(amount - amount / (1. + vat_rate.rate), 2);
+            (self.rounding_mode.round(amount - vat_amount, 2), vat_amount)
+        } else {
+            (amount, self.rounding_mode.round(amount * vat_rate.rate, 2))
+        };
+        Ok(Vat {
+            amount_excluding_vat,
+            amount_including_vat: self
+                .rounding_mode
+                .round(amount_excluding_vat + vat_amount, 2),
+            vat_amount,
+            vat_category: vat_rate.category.clone(),
+            vat_rate: vat_rate.rate,
+            country: Country {
+                code: country_code,
+                name: String::new(),
+            },
+        })
+    }
+
+    /// Returns the rate of a category in a country.
+    pub fn rate<S: AsRef<str>>(&self, country_code: S, category: &VatCategory) -> Result<&VatRate> {
+        let country_code = country_code.as_ref();
+        self.rates(country_code)
+            .and_then(|rates| rates.for_category(category))
+            .ok_or_else(|| Error::MissingVatRate(format!("{country_code}/{category}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::env;
+
+    fn vat_table() -> VatTable {
+        let vat_rate = |rate, category: &str| VatRate {
+            country_code: String::from("DE"),
+            rate,
+            category: VatCategory::from(category),
+            description: String::new(),
+        };
+        let mut vat_table = VatTable::new();
+        vat_table.insert(
+            "de",
+            VatRates(vec![vat_rate(0.19, "standard"), vat_rate(0.07, "books")]),
+        );
+        vat_table
+    }
+
+    #[test]
+    fn test_calculate() -> Result<()> {
+        let vat_table = vat_table();
+        let vat = vat_table.calculate(200., "DE", false, None)?;
+        assert_eq!(
+            (200., 38., 238.),
+            (
+                vat.amount_excluding_vat,
+                vat.vat_amount,
+                vat.amount_including_vat
+            )
+        );
+        let vat = vat_table.calculate(10., "DE", true, Some(VatCategory::Books))?;
+        assert_eq!(
+            (9.35, 0.65, 10.),
+            (
+                vat.amount_excluding_vat,
+                vat.vat_amount,
+                vat.amount_including_vat
+            )
+        );
+        assert!(matches!(
+            vat_table.calculate(10., "DE", false, Some(VatCategory::Water)),
+            Err(Error::MissingVatRate(rate)) if rate == "DE/water"
+        ));
+        assert!(matches!(
+            vat_table.calculate(10., "FR", false, None),
+            Err(Error::MissingVatRate(rate)) if rate == "FR/standard"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_and_load() -> Result<()> {
+        let path = env::temp_dir().join(format!("abstractapi-vat-table-{}", std::process::id()));
+        let mut vat_table = vat_table();
+        vat_table.set_rounding_mode(RoundingMode::HalfEven);
+        vat_table.save(&path)?;
+        let loaded = VatTable::load(&path)?;
+        fs::remove_file(path)?;
+        assert_eq!(vat_table, loaded);
+        assert!(!loaded.is_stale());
+        let later = SystemTime::now() + DEFAULT_REFRESH_INTERVAL;
+        assert_eq!(vec![String::from("DE")], loaded.stale_countries_at(later));
+        Ok(())
+    }
+}
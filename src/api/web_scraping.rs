@@ -0,0 +1,24 @@
+/// Options of a web scraping request, the upstream defaults apply to unset options.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ScrapeOptions {
+    /// Whether to render the JavaScript of the page in a headless browser.
+    pub render_js: Option<bool>,
+    /// Country of the proxy that the page is requested from (ISO 3166-1 alpha-2 code).
+    pub proxy_country: Option<String>,
+    /// Headers that are sent with the request.
+    pub headers: Vec<(String, String)>,
+}
+
+impl ScrapeOptions {
+    /// Returns the query parameters of the options.
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut query_pairs = Vec::new();
+        if let Some(render_js) = self.render_js {
+            query_pairs.push(("render_js", render_js.to_string()));
+        }
+        if let Some(proxy_country) = &self.proxy_country {
+            query_pairs.push(("proxy_country", proxy_country.to_string()));
+        }
+        query_pairs
+    }
+}
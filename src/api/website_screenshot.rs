@@ -0,0 +1,67 @@
+use std::fmt;
+
+/// Image format of a screenshot.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportFormat {
+    /// PNG image.
+    #[default]
+    Png,
+    /// JPEG image.
+    Jpeg,
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Png => "png",
+                Self::Jpeg => "jpeg",
+            }
+        )
+    }
+}
+
+/// Options of a website screenshot, the upstream defaults apply to unset options.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ScreenshotOptions {
+    /// Whether to capture the full page instead of the viewport.
+    pub capture_full_page: Option<bool>,
+    /// Width of the viewport in pixels.
+    pub width: Option<u32>,
+    /// Height of the viewport in pixels.
+    pub height: Option<u32>,
+    /// Seconds to wait before capturing the screenshot.
+    pub delay: Option<u32>,
+    /// CSS that is injected into the page before capturing it.
+    pub css_injection: Option<String>,
+    /// Image format of the screenshot.
+    pub export_format: Option<ExportFormat>,
+}
+
+impl ScreenshotOptions {
+    /// Returns the query parameters of the options.
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut query_pairs = Vec::new();
+        if let Some(capture_full_page) = self.capture_full_page {
+            query_pairs.push(("capture_full_page", capture_full_page.to_string()));
+        }
+        if let Some(width) = self.width {
+            query_pairs.push(("width", width.to_string()));
+        }
+        if let Some(height) = self.height {
+            query_pairs.push(("height", height.to_string()));
+        }
+        if let Some(delay) = self.delay {
+            query_pairs.push(("delay", delay.to_string()));
+        }
+        if let Some(css_injection) = &self.css_injection {
+            query_pairs.push(("css_injection", css_injection.to_string()));
+        }
+        if let Some(export_format) = self.export_format {
+            query_pairs.push(("export_format", export_format.to_string()));
+        }
+        query_pairs
+    }
+}
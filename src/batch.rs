@@ -0,0 +1,329 @@
+use crate::error::{Error, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Mutex, PoisonError};
+use std::thread;
+
+/// Batch enrichment of companies.
+pub mod company;
+/// Batch validation of email lists.
+pub mod email;
+
+/// Outcome of a single lookup in a batch.
+pub type Outcome<T> = std::result::Result<T, String>;
+
+/// Summary of a batch run.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchSummary {
+    /// Number of input rows.
+    pub rows: usize,
+    /// Number of unique inputs after deduplication.
+    pub unique_inputs: usize,
+    /// Number of inputs that are looked up in this run.
+    pub requested: usize,
+    /// Number of inputs that are restored from the checkpoint.
+    pub restored: usize,
+    /// Number of rows that failed.
+    pub failed: usize,
+}
+
+/// Input value of a batch with its position in the source.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BatchInput {
+    /// Line number of the value in the source, starting from 1.
+    pub line: u64,
+    /// Trimmed value.
+    pub value: String,
+}
+
+/// Format of the batch output files.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputFormat {
+    /// Comma separated values.
+    #[default]
+    Csv,
+    /// Newline delimited JSON.
+    Ndjson,
+}
+
+impl OutputFormat {
+    /// Returns the file extension of the format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Ndjson => "ndjson",
+        }
+    }
+}
+
+/// Entry of a checkpoint file.
+#[derive(Serialize, Deserialize)]
+struct CheckpointEntry<T> {
+    key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Append-only log of finished lookups that allows resuming a batch.
+///
+/// Each outcome is written as a JSON line as soon as it is recorded,
+/// so a crashed or aborted batch does not request the same input twice.
+#[derive(Debug)]
+pub struct Checkpoint<T> {
+    file: File,
+    outcomes: HashMap<String, Outcome<T>>,
+}
+
+impl<T: Serialize + DeserializeOwned> Checkpoint<T> {
+    /// Opens the checkpoint file at the given path, creating it if it does not exist.
+    ///
+    /// Lines that cannot be parsed (e.g. an interrupted write) are ignored.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let contents = if path.as_ref().exists() {
+            fs::read_to_string(&path)?
+        } else {
+            String::new()
+        };
+        let mut outcomes = HashMap::new();
+        for line in contents.lines() {
+            if let Ok(entry) = serde_json::from_str::<CheckpointEntry<T>>(line) {
+                let outcome = match (entry.result, entry.error) {
+                    (Some(result), _) => Ok(result),
+                    (None, error) => Err(error.unwrap_or_default()),
+                };
+                outcomes.insert(entry.key, outcome);
+            }
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if !contents.is_empty() && !contents.ends_with('\n') {
+            // Terminate the interrupted line.
+            writeln!(file)?;
+        }
+        Ok(Self { file, outcomes })
+    }
+
+    /// Returns the recorded outcome for the given key.
+    pub fn get(&self, key: &str) -> Option<&Outcome<T>> {
+        self.outcomes.get(key)
+    }
+
+    /// Returns whether an outcome is recorded for the given key.
+    pub fn contains(&self, key: &str) -> bool {
+        self.outcomes.contains_key(key)
+    }
+
+    /// Returns the number of recorded outcomes.
+    pub fn len(&self) -> usize {
+        self.outcomes.len()
+    }
+
+    /// Returns whether the checkpoint is empty.
+    pub fn is_empty(&self) -> bool {
+        self.outcomes.is_empty()
+    }
+
+    /// Records the outcome for the given key and writes it to the file.
+    pub fn record(&mut self, key: String, outcome: Outcome<T>) -> Result<()> {
+        let entry = match &outcome {
+            Ok(result) => CheckpointEntry {
+                key: key.clone(),
+                result: Some(result),
+                error: None,
+            },
+            Err(error) => CheckpointEntry {
+                key: key.clone(),
+                result: None,
+                error: Some(error.to_string()),
+            },
+        };
+        serde_json::to_writer(&mut self.file, &entry).map_err(std::io::Error::from)?;
+        writeln!(self.file)?;
+        self.file.flush()?;
+        self.outcomes.insert(key, outcome);
+        Ok(())
+    }
+}
+
+/// Returns whether the error is caused by the input of a lookup.
+///
+/// Other errors (e.g. exhausted quota or network errors) abort the batch
+/// so that the input is retried when the batch is resumed.
+pub(crate) fn is_input_error(error: &Error) -> bool {
+    matches!(error, Error::InvalidCompanyLookup(_))
+        || matches!(error.status_code(), Some(400) | Some(404))
+}
+
+/// Looks up the batch inputs on the given number of threads.
+///
+/// The outputs are handled on the current thread in the order they finish,
+/// so they are matched to the inputs by the line numbers.
+/// If handling an output fails, no more inputs are looked up and the first error is returned.
+pub fn lookup_concurrent<T, L, H>(
+    inputs: Vec<BatchInput>,
+    concurrency: usize,
+    lookup: L,
+    handle: H,
+) -> Result<()>
+where
+    T: Send,
+    L: Fn(&str) -> T + Sync,
+    H: FnMut(BatchInput, T) -> Result<()>,
+{
+    for_each_concurrent(inputs, concurrency, |input| lookup(&input.value), handle)
+}
+
+/// Processes the inputs on the given number of threads.
+///
+/// The outputs are handled on the current thread in the order they finish.
+/// If handling an output fails, no more inputs are processed, the outputs that are
+/// already in flight are still handled and the first error is returned.
+pub(crate) fn for_each_concurrent<I, T, P, H>(
+    inputs: Vec<I>,
+    concurrency: usize,
+    process: P,
+    mut handle: H,
+) -> Result<()>
+where
+    I: Send,
+    T: Send,
+    P: Fn(&I) -> T + Sync,
+    H: FnMut(I, T) -> Result<()>,
+{
+    let queue = Mutex::new(inputs.into_iter());
+    let stop = AtomicBool::new(false);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..concurrency.max(1) {
+            let (queue, stop, process, sender) = (&queue, &stop, &process, sender.clone());
+            scope.spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let input = queue.lock().unwrap_or_else(PoisonError::into_inner).next();
+                    let input = match input {
+                        Some(input) => input,
+                        None => break,
+                    };
+                    let output = process(&input);
+                    if sender.send((input, output)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+        let mut result = Ok(());
+        for (input, output) in receiver {
+            if let Err(e) = handle(input, output) {
+                stop.store(true, Ordering::Relaxed);
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+        result
+    })
+}
+
+/// Reads the inputs of a batch, one value per line or the values of a CSV column.
+///
+/// Values are trimmed and empty values are skipped, while the line numbers
+/// still refer to the source (including the CSV header).
+pub fn read_inputs<R: Read>(input: R, column: Option<&str>) -> Result<Vec<BatchInput>> {
+    let inputs = match column {
+        Some(column) => {
+            let mut reader = csv::Reader::from_reader(input);
+            let column = column_index(reader.headers()?, column)?;
+            reader
+                .records()
+                .map(|record| {
+                    let record = record?;
+                    Ok(BatchInput {
+                        line: record.position().map_or(0, |position| position.line()),
+                        value: record.get(column).unwrap_or_default().trim().to_string(),
+                    })
+                })
+                .collect::<Result<Vec<BatchInput>>>()?
+        }
+        None => BufReader::new(input)
+            .lines()
+            .zip(1..)
+            .map(|(line, number)| {
+                Ok(BatchInput {
+                    line: number,
+                    value: line?.trim().to_string(),
+                })
+            })
+            .collect::<Result<Vec<BatchInput>>>()?,
+    };
+    Ok(inputs
+        .into_iter()
+        .filter(|input| !input.value.is_empty())
+        .collect())
+}
+
+/// Returns the index of the given column in the CSV headers.
+pub(crate) fn column_index(headers: &csv::StringRecord, column: &str) -> Result<usize> {
+    headers
+        .iter()
+        .position(|header| header.trim() == column)
+        .ok_or_else(|| Error::MissingColumn(column.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::env;
+
+    #[test]
+    fn test_checkpoint() -> Result<()> {
+        let path = env::temp_dir().join(format!("abstractapi-checkpoint-{}", std::process::id()));
+        let mut checkpoint = Checkpoint::<u32>::open(&path)?;
+        checkpoint.record(String::from("a"), Ok(1))?;
+        checkpoint.record(String::from("b"), Err(String::from("error")))?;
+        drop(checkpoint);
+        OpenOptions::new()
+            .append(true)
+            .open(&path)?
+            .write_all(b"{\"key\":\"c\",\"res")?;
+        let checkpoint = Checkpoint::<u32>::open(&path)?;
+        assert_eq!(2, checkpoint.len());
+        assert_eq!(Some(&Ok(1)), checkpoint.get("a"));
+        assert_eq!(Some(&Err(String::from("error"))), checkpoint.get("b"));
+        fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_inputs() -> Result<()> {
+        let to_pairs = |inputs: Vec<BatchInput>| {
+            inputs
+                .into_iter()
+                .map(|input| (input.line, input.value))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![(1, String::from("1.2.3.4")), (3, String::from("5.6.7.8"))],
+            to_pairs(read_inputs(" 1.2.3.4\n\n5.6.7.8 \n".as_bytes(), None)?)
+        );
+        let csv = "name,email\nJohn,john@example.com\nJane,\nJoe, joe@example.com\n";
+        assert_eq!(
+            vec![
+                (2, String::from("john@example.com")),
+                (4, String::from("joe@example.com"))
+            ],
+            to_pairs(read_inputs(csv.as_bytes(), Some("email"))?)
+        );
+        assert!(matches!(
+            read_inputs(csv.as_bytes(), Some("phone")),
+            Err(Error::MissingColumn(column)) if column == "phone"
+        ));
+        Ok(())
+    }
+}
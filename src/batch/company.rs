@@ -0,0 +1,214 @@
+use super::{column_index, is_input_error, BatchSummary, Checkpoint};
+use crate::api::{CompanyDetails, CompanyLookup};
+use crate::error::Result;
+use crate::AbstractApi;
+use std::collections::HashSet;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+/// Columns that are appended to the output rows.
+const OUTPUT_COLUMNS: &[&str] = &[
+    "name",
+    "domain",
+    "year_founded",
+    "industry",
+    "employees_count",
+    "locality",
+    "country",
+    "linkedin_url",
+    "enrichment_error",
+];
+
+/// Enriches the domains (or email addresses) in a CSV column with company details.
+///
+/// Inputs are normalized and deduplicated, so each domain is requested only once.
+/// Outcomes are stored in a checkpoint file, which makes it possible to resume
+/// the batch after a crash or an exhausted quota without paying twice.
+///
+/// Requests are sent sequentially and obey the rate limit of the client
+/// (see [`AbstractApi::set_rate_limit`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompanyEnrichmentBatch {
+    column: String,
+    checkpoint_path: PathBuf,
+}
+
+impl CompanyEnrichmentBatch {
+    /// Creates a new batch that reads the given column and uses the given checkpoint file.
+    pub fn new<S: Into<String>, P: Into<PathBuf>>(column: S, checkpoint_path: P) -> Self {
+        Self {
+            column: column.into(),
+            checkpoint_path: checkpoint_path.into(),
+        }
+    }
+
+    /// Runs the batch and writes the input rows with the company details as CSV.
+    ///
+    /// The output is only written when all the inputs are processed. Errors that
+    /// are not caused by the input (e.g. quota or network errors) abort the batch.
+    pub fn run<R: Read, W: Write>(
+        &self,
+        abstractapi: &AbstractApi,
+        input: R,
+        output: W,
+    ) -> Result<BatchSummary> {
+        self.run_with(input, output, |domain| {
+            abstractapi.get_company_details(CompanyLookup::Domain(domain.to_string()))
+        })
+    }
+
+    /// Runs the batch with the given lookup for each unique domain.
+    fn run_with<R, W, L>(&self, input: R, output: W, mut lookup: L) -> Result<BatchSummary>
+    where
+        R: Read,
+        W: Write,
+        L: FnMut(&str) -> Result<CompanyDetails>,
+    {
+        let mut reader = csv::Reader::from_reader(input);
+        let headers = reader.headers()?.clone();
+        let column = column_index(&headers, &self.column)?;
+        let records = reader.records().collect::<csv::Result<Vec<_>>>()?;
+        let mut checkpoint = Checkpoint::<CompanyDetails>::open(&self.checkpoint_path)?;
+        let mut summary = BatchSummary {
+            rows: records.len(),
+            ..BatchSummary::default()
+        };
+
+        let domains = records
+            .iter()
+            .map(|record| CompanyLookup::parse(record.get(column).unwrap_or_default()).domain())
+            .collect::<Vec<_>>();
+        let mut unique_domains = HashSet::new();
+        for domain in domains.iter().flatten() {
+            if !unique_domains.insert(domain) {
+                continue;
+            }
+            if checkpoint.contains(domain) {
+                summary.restored += 1;
+                continue;
+            }
+            summary.requested += 1;
+            let outcome = match lookup(domain) {
+                Ok(company_details) => Ok(company_details),
+                Err(e) if is_input_error(&e) => Err(e.to_string()),
+                Err(e) => return Err(e),
+            };
+            checkpoint.record(domain.to_string(), outcome)?;
+        }
+        summary.unique_inputs = unique_domains.len();
+
+        let mut writer = csv::Writer::from_writer(output);
+        writer.write_record(headers.iter().chain(OUTPUT_COLUMNS.iter().copied()))?;
+        for (record, domain) in records.iter().zip(domains) {
+            let outcome = domain.map_err(|e| e.to_string()).and_then(|domain| {
+                checkpoint
+                    .get(&domain)
+                    .cloned()
+                    .unwrap_or_else(|| Err(format!("No outcome for `{domain}`")))
+            });
+            let columns = match outcome {
+                Ok(company_details) => company_columns(company_details),
+                Err(e) => {
+                    summary.failed += 1;
+                    let mut columns = vec![String::new(); OUTPUT_COLUMNS.len() - 1];
+                    columns.push(e);
+                    columns
+                }
+            };
+            writer.write_record(record.iter().chain(columns.iter().map(String::as_str)))?;
+        }
+        writer.flush()?;
+        Ok(summary)
+    }
+}
+
+/// Returns the output columns of the company details.
+fn company_columns(company_details: CompanyDetails) -> Vec<String> {
+    let to_string = |value: Option<i64>| value.map(|v| v.to_string()).unwrap_or_default();
+    vec![
+        company_details.name.unwrap_or_default(),
+        company_details.domain,
+        to_string(company_details.year_founded),
+        company_details.industry.unwrap_or_default(),
+        to_string(company_details.employees_count),
+        company_details.locality.unwrap_or_default(),
+        company_details.country.unwrap_or_default(),
+        company_details.linkedin_url.unwrap_or_default(),
+        String::new(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{Error, HttpStatus};
+    use pretty_assertions::assert_eq;
+    use std::{env, fs};
+
+    #[test]
+    fn test_run() -> Result<()> {
+        let path = env::temp_dir().join(format!("abstractapi-company-{}", std::process::id()));
+        let batch = CompanyEnrichmentBatch::new("website", &path);
+        let input = "id,website\n\
+            1,google.com\n\
+            2,https://www.Google.com/about\n\
+            3,mark@google.com\n\
+            4,localhost\n\
+            5,unknown.com\n";
+        let http_status = |code| {
+            Error::HttpStatus(HttpStatus {
+                code,
+                message: String::new(),
+            })
+        };
+        let mut requested = Vec::new();
+        let mut output = Vec::new();
+        let summary = batch.run_with(input.as_bytes(), &mut output, |domain| {
+            requested.push(domain.to_string());
+            match domain {
+                "google.com" => Ok(CompanyDetails {
+                    name: Some(String::from("Google")),
+                    domain: domain.to_string(),
+                    ..CompanyDetails::default()
+                }),
+                _ => Err(http_status(404)),
+            }
+        })?;
+        assert_eq!(vec!["google.com", "unknown.com"], requested);
+        assert_eq!(
+            BatchSummary {
+                rows: 5,
+                unique_inputs: 2,
+                requested: 2,
+                restored: 0,
+                failed: 2,
+            },
+            summary
+        );
+        let output = String::from_utf8_lossy(&output);
+        let rows = output.lines().collect::<Vec<&str>>();
+        assert_eq!("3,mark@google.com,Google,google.com,,,,,,,", rows[3]);
+        assert_eq!(
+            "4,localhost,,,,,,,,,Invalid company lookup: `localhost`",
+            rows[4]
+        );
+        assert!(
+            rows[5].ends_with("HTTP status error: `404: `"),
+            "{}",
+            rows[5]
+        );
+
+        // The outcomes are restored from the checkpoint, while quota errors abort the batch.
+        let input = format!("{input}6,example.com\n");
+        let summary = batch.run_with(input.as_bytes(), Vec::new(), |domain| {
+            assert_eq!("example.com", domain);
+            Ok(CompanyDetails::default())
+        })?;
+        assert_eq!((2, 1), (summary.restored, summary.requested));
+        let input = format!("{input}7,example.org\n");
+        let result = batch.run_with(input.as_bytes(), Vec::new(), |_| Err(http_status(429)));
+        assert!(matches!(result, Err(Error::HttpStatus(status)) if status.code == 429));
+        fs::remove_file(path)?;
+        Ok(())
+    }
+}
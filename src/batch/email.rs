@@ -0,0 +1,322 @@
+use super::{column_index, for_each_concurrent, is_input_error, BatchSummary, Checkpoint};
+use super::{Outcome, OutputFormat};
+use crate::api::{Deliverability, EmailDetails};
+use crate::error::Result;
+use crate::AbstractApi;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{BufWriter, Read, Write};
+use std::path::PathBuf;
+
+/// Columns that are appended to the output rows in CSV format.
+const OUTPUT_COLUMNS: &[&str] = &["deliverability", "quality_score", "autocorrect", "error"];
+
+/// Category of a validated email address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailBucket {
+    /// Deliverable address that is not a catch-all or a role account.
+    Deliverable,
+    /// Undeliverable address.
+    Undeliverable,
+    /// Catch-all or role account, or an address with unknown deliverability.
+    Risky,
+    /// Address with a disposable domain.
+    Disposable,
+    /// Address that has an autocorrect suggestion.
+    Autocorrected,
+    /// Address that could not be validated.
+    Failed,
+}
+
+impl EmailBucket {
+    /// All the buckets.
+    pub const ALL: [Self; 6] = [
+        Self::Deliverable,
+        Self::Undeliverable,
+        Self::Risky,
+        Self::Disposable,
+        Self::Autocorrected,
+        Self::Failed,
+    ];
+
+    /// Returns the bucket of the email validation result.
+    ///
+    /// Disposable addresses and autocorrect suggestions take precedence over deliverability.
+    pub fn classify(email_details: &EmailDetails) -> Self {
+        if email_details.is_disposable_email.value {
+            Self::Disposable
+        } else if email_details.autocorrect.is_some() {
+            Self::Autocorrected
+        } else {
+            match email_details.deliverability {
+                Deliverability::Undeliverable => Self::Undeliverable,
+                Deliverability::Deliverable
+                    if !email_details.is_catchall_email.value
+                        && !email_details.is_role_email.value =>
+                {
+                    Self::Deliverable
+                }
+                _ => Self::Risky,
+            }
+        }
+    }
+}
+
+impl fmt::Display for EmailBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Deliverable => "deliverable",
+                Self::Undeliverable => "undeliverable",
+                Self::Risky => "risky",
+                Self::Disposable => "disposable",
+                Self::Autocorrected => "autocorrected",
+                Self::Failed => "failed",
+            }
+        )
+    }
+}
+
+/// Summary of an email hygiene batch.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EmailHygieneSummary {
+    /// Summary of the batch run.
+    pub batch: BatchSummary,
+    /// Number of rows in each bucket.
+    pub buckets: BTreeMap<EmailBucket, usize>,
+}
+
+/// NDJSON record of an output row.
+#[derive(Serialize)]
+struct Record<'a> {
+    row: BTreeMap<&'a str, &'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<&'a EmailDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+}
+
+/// Validates the email addresses in a CSV column and sorts the rows into buckets.
+///
+/// Addresses are deduplicated case-insensitively and validated on multiple threads,
+/// which share the rate limit of the client (see [`AbstractApi::set_rate_limit`]).
+/// Each bucket is written to a separate file (e.g. `deliverable.csv`) in the output
+/// directory with the original rows. Outcomes are stored in a checkpoint file,
+/// so an interrupted batch can be resumed without validating the same address twice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailHygieneBatch {
+    column: String,
+    checkpoint_path: PathBuf,
+    output_dir: PathBuf,
+    output_format: OutputFormat,
+    concurrency: usize,
+    auto_correct: bool,
+}
+
+impl EmailHygieneBatch {
+    /// Creates a new batch that reads the given column, uses the given checkpoint file
+    /// and writes the buckets into the given directory.
+    pub fn new<S, P1, P2>(column: S, checkpoint_path: P1, output_dir: P2) -> Self
+    where
+        S: Into<String>,
+        P1: Into<PathBuf>,
+        P2: Into<PathBuf>,
+    {
+        Self {
+            column: column.into(),
+            checkpoint_path: checkpoint_path.into(),
+            output_dir: output_dir.into(),
+            output_format: OutputFormat::default(),
+            concurrency: 4,
+            auto_correct: true,
+        }
+    }
+
+    /// Sets the format of the output files.
+    pub fn set_output_format(&mut self, output_format: OutputFormat) {
+        self.output_format = output_format;
+    }
+
+    /// Sets the number of addresses that are validated in parallel.
+    pub fn set_concurrency(&mut self, concurrency: usize) {
+        self.concurrency = concurrency;
+    }
+
+    /// Sets whether the API should suggest corrections for the addresses.
+    pub fn set_auto_correct(&mut self, auto_correct: bool) {
+        self.auto_correct = auto_correct;
+    }
+
+    /// Runs the batch and writes the bucket files.
+    ///
+    /// The files are only written when all the addresses are processed. Errors that
+    /// are not caused by the input (e.g. quota or network errors) abort the batch.
+    pub fn run<R: Read>(&self, abstractapi: &AbstractApi, input: R) -> Result<EmailHygieneSummary> {
+        let mut reader = csv::Reader::from_reader(input);
+        let headers = reader.headers()?.clone();
+        let column = column_index(&headers, &self.column)?;
+        let records = reader.records().collect::<csv::Result<Vec<_>>>()?;
+        let mut checkpoint = Checkpoint::<EmailDetails>::open(&self.checkpoint_path)?;
+        let mut summary = EmailHygieneSummary::default();
+        summary.batch.rows = records.len();
+
+        let emails = records
+            .iter()
+            .map(|record| record.get(column).unwrap_or_default().trim().to_lowercase())
+            .collect::<Vec<String>>();
+        let mut unique_emails = HashSet::new();
+        let mut pending_emails = Vec::new();
+        for email in emails.iter().filter(|email| !email.is_empty()) {
+            if unique_emails.insert(email) {
+                if checkpoint.contains(email) {
+                    summary.batch.restored += 1;
+                } else {
+                    pending_emails.push(email.to_string());
+                }
+            }
+        }
+        summary.batch.unique_inputs = unique_emails.len();
+        summary.batch.requested = pending_emails.len();
+        for_each_concurrent(
+            pending_emails,
+            self.concurrency,
+            |email| abstractapi.validate_email(email, self.auto_correct),
+            |email, result| {
+                let outcome = match result {
+                    Ok(email_details) => Ok(email_details),
+                    Err(e) if is_input_error(&e) => Err(e.to_string()),
+                    Err(e) => return Err(e),
+                };
+                checkpoint.record(email, outcome)
+            },
+        )?;
+
+        fs::create_dir_all(&self.output_dir)?;
+        let mut writers = BTreeMap::new();
+        for bucket in EmailBucket::ALL {
+            let path = self
+                .output_dir
+                .join(format!("{bucket}.{}", self.output_format.extension()));
+            let mut writer = BufWriter::new(File::create(path)?);
+            if self.output_format == OutputFormat::Csv {
+                let mut csv_writer = csv::Writer::from_writer(&mut writer);
+                csv_writer.write_record(headers.iter().chain(OUTPUT_COLUMNS.iter().copied()))?;
+                csv_writer.flush()?;
+            }
+            writers.insert(bucket, writer);
+            summary.buckets.insert(bucket, 0);
+        }
+        for (record, email) in records.iter().zip(&emails) {
+            let outcome: Outcome<EmailDetails> = if email.is_empty() {
+                Err(String::from("Email address is empty"))
+            } else {
+                checkpoint
+                    .get(email)
+                    .cloned()
+                    .unwrap_or_else(|| Err(format!("No outcome for `{email}`")))
+            };
+            let bucket = match &outcome {
+                Ok(email_details) => EmailBucket::classify(email_details),
+                Err(_) => {
+                    summary.batch.failed += 1;
+                    EmailBucket::Failed
+                }
+            };
+            *summary.buckets.entry(bucket).or_default() += 1;
+            let writer = writers.get_mut(&bucket).expect("bucket writer");
+            match self.output_format {
+                OutputFormat::Csv => {
+                    let columns = match &outcome {
+                        Ok(email_details) => vec![
+                            email_details.deliverability.to_string(),
+                            email_details.quality_score.to_string(),
+                            email_details.autocorrect.clone().unwrap_or_default(),
+                            String::new(),
+                        ],
+                        Err(e) => vec![String::new(), String::new(), String::new(), e.to_string()],
+                    };
+                    let mut csv_writer = csv::WriterBuilder::new()
+                        .has_headers(false)
+                        .from_writer(&mut *writer);
+                    csv_writer
+                        .write_record(record.iter().chain(columns.iter().map(String::as_str)))?;
+                    csv_writer.flush()?;
+                }
+                OutputFormat::Ndjson => {
+                    let record = Record {
+                        row: headers.iter().zip(record.iter()).collect(),
+                        result: outcome.as_ref().ok(),
+                        error: outcome.as_ref().err().map(String::as_str),
+                    };
+                    serde_json::to_writer(&mut *writer, &record).map_err(std::io::Error::from)?;
+                    writeln!(writer)?;
+                }
+            }
+        }
+        for writer in writers.values_mut() {
+            writer.flush()?;
+        }
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::EmailFlag;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_classify() {
+        let flag = |value| EmailFlag {
+            value,
+            ..EmailFlag::default()
+        };
+        let email_details = EmailDetails {
+            deliverability: Deliverability::Deliverable,
+            ..EmailDetails::default()
+        };
+        assert_eq!(
+            EmailBucket::Deliverable,
+            EmailBucket::classify(&email_details)
+        );
+        for (bucket, email_details) in [
+            (
+                EmailBucket::Risky,
+                EmailDetails {
+                    is_role_email: flag(true),
+                    ..email_details.clone()
+                },
+            ),
+            (
+                EmailBucket::Autocorrected,
+                EmailDetails {
+                    autocorrect: Some(String::from("test@gmail.com")),
+                    ..email_details.clone()
+                },
+            ),
+            (
+                EmailBucket::Disposable,
+                EmailDetails {
+                    is_disposable_email: flag(true),
+                    deliverability: Deliverability::Undeliverable,
+                    ..email_details.clone()
+                },
+            ),
+            (
+                EmailBucket::Undeliverable,
+                EmailDetails {
+                    deliverability: Deliverability::Undeliverable,
+                    ..email_details
+                },
+            ),
+        ] {
+            assert_eq!(bucket, EmailBucket::classify(&email_details));
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use crate::output::Format;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+/// Command-line client for Abstract API.
+///
+/// API keys are read from the environment (e.g. `GEOLOCATION_API_KEY`)
+/// or from the `[api_keys]` table of the config file.
+/// The interactive shell is started if no command is given.
+///
+/// The screenshot, scraping, image processing and avatar APIs return images
+/// or pages, so they are only available in the library.
+#[derive(Debug, Parser)]
+#[command(name = "abstractapi", version, about)]
+pub struct Args {
+    /// Output format [default: json, or tree in the interactive shell].
+    #[arg(short, long, value_enum, global = true)]
+    pub format: Option<Format>,
+    /// Path of the config file.
+    #[arg(short, long, env = "ABSTRACTAPI_CONFIG", global = true)]
+    pub config: Option<PathBuf>,
+    /// Read the main argument of the command from a file (`-` for stdin)
+    /// and print an NDJSON record for each input.
+    #[arg(short, long, value_name = "PATH", global = true)]
+    pub input: Option<String>,
+    /// Read the inputs from a column of a CSV file instead of the lines.
+    #[arg(long, requires = "input", global = true)]
+    pub column: Option<String>,
+    /// Number of concurrent lookups in batch mode.
+    #[arg(long, default_value_t = 4, global = true)]
+    pub concurrency: usize,
+    /// Minimum interval between the requests in milliseconds.
+    #[arg(long, env = "ABSTRACTAPI_RATE_LIMIT", global = true)]
+    pub rate_limit: Option<u64>,
+    /// Command to run.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Commands of the tool, one for each client method with a JSON response.
+///
+/// The last positional argument is the main argument, which is read
+/// from the input file in batch mode and required otherwise.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Get the location of an IP address.
+    Geolocate {
+        /// IP address, the address of the caller if it is not set.
+        #[arg(default_value = "")]
+        ip_address: String,
+    },
+    /// Get the holidays of a country.
+    Holidays {
+        /// ISO 3166-1 alpha-2 code of the country.
+        country: Option<String>,
+        /// Year of the holidays.
+        #[arg(long, default_value = "")]
+        year: String,
+        /// Month of the holidays.
+        #[arg(long, default_value = "")]
+        month: String,
+        /// Day of the holidays.
+        #[arg(long, default_value = "")]
+        day: String,
+    },
+    /// Get exchange rates.
+    #[command(subcommand)]
+    Rates(RatesCommand),
+    /// Get the details of a company by its domain or an email address.
+    Company {
+        /// Domain or email address.
+        lookup: Option<String>,
+    },
+    /// Get or convert the time of a location.
+    #[command(subcommand)]
+    Time(TimeCommand),
+    /// Validate an email address.
+    Email {
+        /// Email address.
+        email: Option<String>,
+        /// Suggest a correction for a mistyped address.
+        #[arg(long)]
+        auto_correct: bool,
+    },
+    /// Validate a phone number.
+    Phone {
+        /// Phone number.
+        phone: Option<String>,
+        /// ISO 3166-1 alpha-2 code of the country for national numbers.
+        #[arg(long)]
+        country: Option<String>,
+    },
+    /// Validate VAT numbers and calculate VAT.
+    #[command(subcommand)]
+    Vat(VatCommand),
+    /// Validate an IBAN.
+    Iban {
+        /// IBAN.
+        iban: Option<String>,
+    },
+    /// Get the security and network details of an IP address.
+    IpIntelligence {
+        /// IP address, the address of the caller if it is not set.
+        #[arg(default_value = "")]
+        ip_address: String,
+    },
+    /// Get the reputation of an email address.
+    EmailReputation {
+        /// Email address.
+        email: Option<String>,
+    },
+}
+
+/// Exchange rates commands.
+#[derive(Debug, Subcommand)]
+pub enum RatesCommand {
+    /// Get the latest exchange rates.
+    Live {
+        /// Base currency.
+        base: Option<String>,
+        /// Target currencies (comma separated), all of them if it is not set.
+        #[arg(long)]
+        target: Option<String>,
+    },
+    /// Get the exchange rates of a date.
+    Historical {
+        /// Base currency.
+        base: String,
+        /// Date (`YYYY-MM-DD`).
+        date: Option<String>,
+        /// Target currencies (comma separated), all of them if it is not set.
+        #[arg(long)]
+        target: Option<String>,
+    },
+    /// Convert an amount between currencies.
+    Convert {
+        /// Base currency.
+        base: String,
+        /// Target currency.
+        target: Option<String>,
+        /// Amount in the base currency.
+        #[arg(long)]
+        amount: Option<u64>,
+        /// Date of the exchange rate (`YYYY-MM-DD`), the latest rate if it is not set.
+        #[arg(long)]
+        date: Option<String>,
+    },
+}
+
+/// Timezone commands.
+#[derive(Debug, Subcommand)]
+pub enum TimeCommand {
+    /// Get the current time of a location.
+    Now {
+        /// Location (e.g. city, address or IP address).
+        location: Option<String>,
+    },
+    /// Convert a time between locations.
+    Convert {
+        /// Location of the time.
+        base_location: String,
+        /// Time (`YYYY-MM-DD HH:MM:SS`).
+        base_datetime: String,
+        /// Location to convert the time to.
+        target_location: Option<String>,
+    },
+}
+
+/// VAT commands.
+#[derive(Debug, Subcommand)]
+pub enum VatCommand {
+    /// Validate a VAT number.
+    Validate {
+        /// VAT number.
+        vat_number: Option<String>,
+    },
+    /// Calculate the VAT of an amount.
+    Calculate {
+        /// Amount.
+        amount: f64,
+        /// ISO 3166-1 alpha-2 code of the country.
+        country: Option<String>,
+        /// Whether the amount includes VAT.
+        #[arg(long)]
+        incl: bool,
+        /// Category of the sale (e.g. `books`).
+        #[arg(long)]
+        category: Option<String>,
+    },
+    /// Get the VAT rates of a country.
+    Rates {
+        /// ISO 3166-1 alpha-2 code of the country.
+        country: Option<String>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_input_position() -> Result<(), clap::Error> {
+        for args in [
+            ["abstractapi", "-i", "-", "phone"],
+            ["abstractapi", "phone", "-i", "-"],
+        ] {
+            let args = Args::try_parse_from(args)?;
+            assert_eq!(Some("-"), args.input.as_deref());
+            assert!(matches!(
+                args.command,
+                Some(Command::Phone { phone: None, .. })
+            ));
+        }
+        let args = Args::try_parse_from([
+            "abstractapi",
+            "--input",
+            "list.csv",
+            "--column",
+            "country",
+            "vat",
+            "rates",
+        ])?;
+        assert_eq!(Some("country"), args.column.as_deref());
+        assert!(matches!(
+            args.command,
+            Some(Command::Vat(VatCommand::Rates { country: None }))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::args::Command;
+use crate::{lookup, Result};
+use abstractapi::batch::{lookup_concurrent, BatchInput};
+use abstractapi::error::Error;
+use abstractapi::AbstractApi;
+use serde::Serialize;
+use serde_json::Value;
+use std::io::Write;
+
+/// NDJSON record of a lookup in batch mode.
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    /// Line number of the input in the source, starting from 1.
+    line: u64,
+    /// Trimmed input value.
+    input: &'a str,
+    /// Result of the lookup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    /// Error of the lookup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<RecordError>,
+}
+
+/// Structured error of a failed lookup.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+struct RecordError {
+    /// Kind of the error (e.g. `RequestError`).
+    kind: String,
+    /// Error message.
+    message: String,
+    /// HTTP status code if the API returned an error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status_code: Option<u16>,
+}
+
+impl From<&Error> for RecordError {
+    fn from(error: &Error) -> Self {
+        Self {
+            kind: error.kind().to_string(),
+            message: error.to_string(),
+            status_code: error.status_code(),
+        }
+    }
+}
+
+/// Runs the command for each input concurrently and writes an NDJSON record
+/// for each of them in the order they finish, with the line numbers of the inputs.
+///
+/// Failed lookups are written as records with an error, so only the errors
+/// of writing the output abort the batch.
+pub fn run<W: Write>(
+    abstractapi: &AbstractApi,
+    command: &Command,
+    inputs: Vec<BatchInput>,
+    concurrency: usize,
+    writer: &mut W,
+) -> Result<()> {
+    lookup_concurrent(
+        inputs,
+        concurrency,
+        |input| lookup(abstractapi, command, Some(input)),
+        |input, output| {
+            let record = match output {
+                Ok(result) => Record {
+                    line: input.line,
+                    input: &input.value,
+                    result: Some(result),
+                    error: None,
+                },
+                Err(e) => Record {
+                    line: input.line,
+                    input: &input.value,
+                    result: None,
+                    error: Some(RecordError::from(&e)),
+                },
+            };
+            serde_json::to_writer(&mut *writer, &record).map_err(std::io::Error::from)?;
+            writeln!(writer)?;
+            writer.flush()?;
+            Ok(())
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_run() -> Result<()> {
+        let abstractapi = AbstractApi::default();
+        let command = Command::Phone {
+            phone: None,
+            country: None,
+        };
+        let mut output = Vec::new();
+        run(
+            &abstractapi,
+            &command,
+            vec![
+                BatchInput {
+                    line: 1,
+                    value: String::from("123"),
+                },
+                BatchInput {
+                    line: 3,
+                    value: String::from("123"),
+                },
+            ],
+            2,
+            &mut output,
+        )?;
+        let output = String::from_utf8_lossy(&output);
+        let records = output.lines().collect::<Vec<&str>>();
+        assert_eq!(2, records.len());
+        for line in [1, 3] {
+            let record = format!(
+                r#"{{"line":{line},"input":"123","error":{{"kind":"InvalidPhoneNumber","message":"Invalid phone number: `123`"}}}}"#
+            );
+            assert!(records.contains(&record.as_str()), "{}", record);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use crate::Result;
+use abstractapi::error::Error;
+use abstractapi::ApiType;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// APIs that the keys can be configured for.
+pub const API_TYPES: [ApiType; 15] = [
+    ApiType::Geolocation,
+    ApiType::Holidays,
+    ApiType::ExchangeRates,
+    ApiType::CompanyEnrichment,
+    ApiType::Timezone,
+    ApiType::EmailValidation,
+    ApiType::PhoneValidation,
+    ApiType::Vat,
+    ApiType::IbanValidation,
+    ApiType::WebsiteScreenshot,
+    ApiType::WebScraping,
+    ApiType::ImageProcessing,
+    ApiType::Avatars,
+    ApiType::IpIntelligence,
+    ApiType::EmailReputation,
+];
+
+/// Configuration file of the tool.
+///
+/// ```toml
+/// [api_keys]
+/// geolocation = "..."
+/// exchange_rates = "..."
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// API keys by the name of the API.
+    #[serde(default)]
+    pub api_keys: HashMap<String, String>,
+}
+
+impl Config {
+    /// Loads the config from the given path, or from the default path if it exists.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match default_path() {
+                Some(path) if path.exists() => path,
+                _ => return Ok(Self::default()),
+            },
+        };
+        let contents = fs::read_to_string(&path)?;
+        toml::from_str(&contents)
+            .map_err(|e| Error::InvalidOptions(format!("{}: {e}", path.display())))
+    }
+
+    /// Returns the API keys from the environment, falling back to the config file.
+    pub fn api_keys(&self) -> Vec<(ApiType, String)> {
+        API_TYPES
+            .iter()
+            .filter_map(|api_type| {
+                let env_key = env::var(format!("{}_API_KEY", api_name(*api_type).to_uppercase()));
+                self.api_key(*api_type, env_key.ok())
+                    .map(|api_key| (*api_type, api_key))
+            })
+            .collect()
+    }
+
+    /// Returns the API key of an API, the key from the environment takes precedence.
+    fn api_key(&self, api_type: ApiType, env_key: Option<String>) -> Option<String> {
+        env_key
+            .or_else(|| self.api_keys.get(api_name(api_type)).cloned())
+            .filter(|api_key| !api_key.is_empty())
+    }
+}
+
+/// Returns the directory of the tool in the user's config directory.
+pub fn config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+        .map(|config_dir| config_dir.join("abstractapi"))
+}
+
+/// Returns the default path of the config file (`abstractapi/config.toml`
+/// in the user's config directory).
+fn default_path() -> Option<PathBuf> {
+    config_dir().map(|config_dir| config_dir.join("config.toml"))
+}
+
+/// Returns the name of an API in the config file.
+///
+/// The environment variable is the uppercased name with the `_API_KEY` suffix.
+pub fn api_name(api_type: ApiType) -> &'static str {
+    match api_type {
+        ApiType::Geolocation => "geolocation",
+        ApiType::Holidays => "holidays",
+        ApiType::ExchangeRates => "exchange_rates",
+        ApiType::CompanyEnrichment => "company_enrichment",
+        ApiType::Timezone => "timezone",
+        ApiType::EmailValidation => "email_validation",
+        ApiType::PhoneValidation => "phone_validation",
+        ApiType::Vat => "vat",
+        ApiType::IbanValidation => "iban_validation",
+        ApiType::WebsiteScreenshot => "website_screenshot",
+        ApiType::WebScraping => "web_scraping",
+        ApiType::ImageProcessing => "image_processing",
+        ApiType::Avatars => "avatars",
+        ApiType::IpIntelligence => "ip_intelligence",
+        ApiType::EmailReputation => "email_reputation",
+    }
+}
+
+/// Returns the API with the given name in the config file.
+pub fn api_type(name: &str) -> Option<ApiType> {
+    API_TYPES
+        .iter()
+        .copied()
+        .find(|api_type| api_name(*api_type) == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_api_keys() -> Result<()> {
+        let path = env::temp_dir().join(format!("abstractapi-config-{}", std::process::id()));
+        fs::write(&path, "[api_keys]\nholidays = \"abc\"\nvat = \"\"\n")?;
+        let config = Config::load(Some(&path))?;
+        fs::remove_file(path)?;
+        assert_eq!(
+            Some(String::from("abc")),
+            config.api_key(ApiType::Holidays, None)
+        );
+        assert_eq!(
+            Some(String::from("xyz")),
+            config.api_key(ApiType::Holidays, Some(String::from("xyz")))
+        );
+        assert_eq!(None, config.api_key(ApiType::Vat, None));
+        assert_eq!(None, config.api_key(ApiType::Geolocation, None));
+        assert_eq!(Some(ApiType::ExchangeRates), api_type("exchange_rates"));
+        assert_eq!(None, api_type("exchange-rates"));
+        Ok(())
+    }
+}
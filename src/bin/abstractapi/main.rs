@@ -0,0 +1,197 @@
+//! Command-line client for Abstract API.
+
+mod args;
+mod batch;
+mod config;
+mod output;
+mod repl;
+mod tree;
+
+use abstractapi::api::{CompanyLookup, VatCategory};
+use abstractapi::batch::read_inputs;
+use abstractapi::error::Error;
+use abstractapi::AbstractApi;
+use args::{Args, Command, RatesCommand, TimeCommand, VatCommand};
+use clap::Parser;
+use config::Config;
+use output::Format;
+use repl::Shell;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs::File;
+use std::io;
+use std::process;
+use std::time::Duration;
+
+/// Type alias for the results of the tool.
+type Result<T> = std::result::Result<T, Error>;
+
+/// Returns the main argument, which is replaced by the input in batch mode.
+///
+/// The argument is checked here instead of by the parser, since `--input`
+/// may be given before the command.
+fn main_arg<'a>(input: Option<&'a str>, value: &'a Option<String>, name: &str) -> Result<&'a str> {
+    input
+        .or(value.as_deref())
+        .ok_or_else(|| missing_argument(name))
+}
+
+/// Returns the error of a command that misses an argument.
+pub(crate) fn missing_argument(name: &str) -> Error {
+    Error::InvalidOptions(format!("missing argument: <{name}>"))
+}
+
+/// Converts the result of a client method into JSON.
+fn to_value<T: Serialize>(result: Result<T>) -> Result<Value> {
+    Ok(serde_json::to_value(result?).map_err(std::io::Error::from)?)
+}
+
+/// Runs the command with the given input as its main argument.
+pub(crate) fn lookup(
+    abstractapi: &AbstractApi,
+    command: &Command,
+    input: Option<&str>,
+) -> Result<Value> {
+    match command {
+        Command::Geolocate { ip_address } => {
+            to_value(abstractapi.get_geolocation(input.unwrap_or(ip_address)))
+        }
+        Command::Holidays {
+            country,
+            year,
+            month,
+            day,
+        } => to_value(abstractapi.get_holidays(
+            main_arg(input, country, "country")?,
+            year,
+            month,
+            day,
+        )),
+        Command::Rates(RatesCommand::Live { base, target }) => to_value(
+            abstractapi
+                .get_latest_exchange_rates(main_arg(input, base, "base")?, target.as_deref()),
+        ),
+        Command::Rates(RatesCommand::Historical { base, date, target }) => {
+            to_value(abstractapi.get_historical_exchange_rates(
+                base.as_str(),
+                target.as_deref(),
+                main_arg(input, date, "date")?,
+            ))
+        }
+        Command::Rates(RatesCommand::Convert {
+            base,
+            target,
+            amount,
+            date,
+        }) => to_value(abstractapi.convert_currency(
+            base.as_str(),
+            main_arg(input, target, "target")?,
+            date.as_deref(),
+            *amount,
+        )),
+        Command::Company { lookup } => to_value(
+            abstractapi
+                .get_company_details(CompanyLookup::parse(main_arg(input, lookup, "lookup")?)),
+        ),
+        Command::Time(TimeCommand::Now { location }) => {
+            to_value(abstractapi.get_current_time(main_arg(input, location, "location")?))
+        }
+        Command::Time(TimeCommand::Convert {
+            base_location,
+            base_datetime,
+            target_location,
+        }) => to_value(abstractapi.convert_time(
+            base_location.as_str(),
+            base_datetime,
+            main_arg(input, target_location, "target_location")?,
+        )),
+        Command::Email {
+            email,
+            auto_correct,
+        } => to_value(abstractapi.validate_email(main_arg(input, email, "email")?, *auto_correct)),
+        Command::Phone { phone, country } => to_value(
+            abstractapi.validate_phone(main_arg(input, phone, "phone")?, country.as_deref()),
+        ),
+        Command::Vat(VatCommand::Validate { vat_number }) => {
+            to_value(abstractapi.validate_vat(main_arg(input, vat_number, "vat_number")?))
+        }
+        Command::Vat(VatCommand::Calculate {
+            amount,
+            country,
+            incl,
+            category,
+        }) => to_value(abstractapi.calculate_vat(
+            *amount,
+            main_arg(input, country, "country")?,
+            *incl,
+            category.as_deref().map(VatCategory::from),
+        )),
+        Command::Vat(VatCommand::Rates { country }) => {
+            to_value(abstractapi.get_vat_rates(main_arg(input, country, "country")?))
+        }
+        Command::Iban { iban } => {
+            to_value(abstractapi.validate_iban(main_arg(input, iban, "iban")?))
+        }
+        Command::IpIntelligence { ip_address } => {
+            to_value(abstractapi.get_ip_intelligence(input.unwrap_or(ip_address)))
+        }
+        Command::EmailReputation { email } => {
+            to_value(abstractapi.get_email_reputation(main_arg(input, email, "email")?))
+        }
+    }
+}
+
+/// Runs the command and writes its result (or the batch records) to the standard output,
+/// or starts the interactive shell.
+fn run(args: Args) -> Result<()> {
+    let config = Config::load(args.config.as_deref())?;
+    let mut abstractapi = AbstractApi::new_with_api_keys(config.api_keys())?;
+    if let Some(rate_limit) = args.rate_limit {
+        abstractapi.set_rate_limit(Duration::from_millis(rate_limit));
+    }
+    let command = match args.command {
+        Some(command) => command,
+        None if args.input.is_some() => {
+            return Err(Error::InvalidOptions(String::from(
+                "a command is required for the input",
+            )))
+        }
+        None => return Shell::new(abstractapi).run(),
+    };
+    let stdout = io::stdout();
+    let mut writer = stdout.lock();
+    match args.input.as_deref() {
+        Some("-") => {
+            let inputs = read_inputs(io::stdin(), args.column.as_deref())?;
+            batch::run(
+                &abstractapi,
+                &command,
+                inputs,
+                args.concurrency,
+                &mut writer,
+            )
+        }
+        Some(path) => {
+            let inputs = read_inputs(File::open(path)?, args.column.as_deref())?;
+            batch::run(
+                &abstractapi,
+                &command,
+                inputs,
+                args.concurrency,
+                &mut writer,
+            )
+        }
+        None => output::write(
+            &lookup(&abstractapi, &command, None)?,
+            args.format.unwrap_or(Format::Json),
+            &mut writer,
+        ),
+    }
+}
+
+fn main() {
+    if let Err(e) = run(Args::parse()) {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
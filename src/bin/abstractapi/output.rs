@@ -0,0 +1,201 @@
+use crate::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+use std::io::Write;
+
+/// Output format of the results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Pretty-printed JSON.
+    Json,
+    /// Aligned table of the flattened fields.
+    Table,
+    /// CSV with a header of the flattened fields.
+    Csv,
+}
+
+/// Writes the value in the given format.
+///
+/// For the table and CSV formats, nested fields are flattened into dotted
+/// names (e.g. `country.code`) and arrays produce a row for each element.
+pub fn write<T: Serialize, W: Write>(value: &T, format: Format, writer: &mut W) -> Result<()> {
+    let value = serde_json::to_value(value).map_err(std::io::Error::from)?;
+    match format {
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *writer, &value).map_err(std::io::Error::from)?;
+            writeln!(writer)?;
+        }
+        Format::Table => write_table(&rows(&value), writer)?,
+        Format::Csv => write_csv(&rows(&value), writer)?,
+    }
+    Ok(())
+}
+
+/// Flattened fields of a row.
+type Row = Vec<(String, String)>;
+
+/// Returns the flattened rows of a value.
+fn rows(value: &Value) -> Vec<Row> {
+    match value {
+        Value::Array(values) => values.iter().map(row).collect(),
+        value => vec![row(value)],
+    }
+}
+
+/// Returns the flattened fields of a value.
+fn row(value: &Value) -> Row {
+    let mut row = Row::new();
+    flatten(value, String::new(), &mut row);
+    row
+}
+
+/// Flattens the value into the row with the given field name prefix.
+fn flatten(value: &Value, name: String, row: &mut Row) {
+    let field_name = |key: &str| {
+        if name.is_empty() {
+            key.to_string()
+        } else {
+            format!("{name}.{key}")
+        }
+    };
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                flatten(value, field_name(key), row);
+            }
+        }
+        Value::Array(values) if values.iter().any(|v| v.is_object() || v.is_array()) => {
+            for (index, value) in values.iter().enumerate() {
+                flatten(value, field_name(&index.to_string()), row);
+            }
+        }
+        Value::Array(values) => row.push((
+            name,
+            values.iter().map(cell).collect::<Vec<String>>().join(", "),
+        )),
+        value => row.push((name, cell(value))),
+    }
+}
+
+/// Returns the text of a scalar value.
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => value.to_string(),
+        value => value.to_string(),
+    }
+}
+
+/// Returns the field names of the rows in the order of their first appearance.
+fn header(rows: &[Row]) -> Vec<&str> {
+    let mut header = Vec::<&str>::new();
+    for (name, _) in rows.iter().flatten() {
+        if !header.contains(&name.as_str()) {
+            header.push(name);
+        }
+    }
+    header
+}
+
+/// Returns the cells of a row in the order of the header.
+fn cells<'a>(row: &'a Row, header: &[&str]) -> Vec<&'a str> {
+    header
+        .iter()
+        .map(|name| {
+            row.iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, value)| value.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Writes the rows as CSV.
+fn write_csv<W: Write>(rows: &[Row], writer: &mut W) -> Result<()> {
+    let header = header(rows);
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer.write_record(&header)?;
+    for row in rows {
+        csv_writer.write_record(cells(row, &header))?;
+    }
+    csv_writer.flush()?;
+    Ok(())
+}
+
+/// Writes the rows as an aligned table.
+///
+/// A single row is written vertically as field/value pairs.
+fn write_table<W: Write>(rows: &[Row], writer: &mut W) -> Result<()> {
+    let table = match rows {
+        [row] => row
+            .iter()
+            .map(|(name, value)| vec![name.as_str(), value.as_str()])
+            .collect::<Vec<Vec<&str>>>(),
+        rows => {
+            let header = header(rows);
+            let mut table = vec![header.clone()];
+            table.extend(rows.iter().map(|row| cells(row, &header)));
+            table
+        }
+    };
+    let columns = table.iter().map(Vec::len).max().unwrap_or_default();
+    let widths = (0..columns)
+        .map(|column| {
+            table
+                .iter()
+                .filter_map(|line| line.get(column))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<usize>>();
+    for line in table {
+        let line = line
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<String>>()
+            .join("  ");
+        writeln!(writer, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn output(value: Value, format: Format) -> Result<String> {
+        let mut output = Vec::new();
+        write(&value, format, &mut output)?;
+        Ok(String::from_utf8_lossy(&output).to_string())
+    }
+
+    #[test]
+    fn test_write() -> Result<()> {
+        let value =
+            json!({"ip": "1.2.3.4", "country": {"code": "FR", "name": null}, "tags": ["a", "b"]});
+        assert_eq!(
+            "ip            1.2.3.4\ncountry.code  FR\ncountry.name\ntags          a, b\n",
+            output(value.clone(), Format::Table)?
+        );
+        assert_eq!(
+            "ip,country.code,country.name,tags\n1.2.3.4,FR,,\"a, b\"\n",
+            output(value, Format::Csv)?
+        );
+        let value =
+            json!([{"rate": 0.2, "category": "standard"}, {"rate": 0.055, "category": "books"}]);
+        assert_eq!(
+            "rate   category\n0.2    standard\n0.055  books\n",
+            output(value.clone(), Format::Table)?
+        );
+        assert_eq!(
+            "rate,category\n0.2,standard\n0.055,books\n",
+            output(value, Format::Csv)?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,381 @@
+use crate::args::Args;
+use crate::config::{self, api_name, api_type, API_TYPES};
+use crate::output::{self, Format};
+use crate::tree::Tree;
+use crate::{batch, lookup, missing_argument, Result};
+use abstractapi::batch::read_inputs;
+use abstractapi::error::Error;
+use abstractapi::{AbstractApi, ApiVersion};
+use clap::{CommandFactory, Parser};
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+use serde_json::Value;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::iter;
+use std::path::Path;
+use std::time::Instant;
+
+/// Commands of the shell that are not client methods.
+const SHELL_COMMANDS: &[(&str, &str)] = &[
+    (":help", "show this help"),
+    (":save <file.json|file.csv>", "save the last response"),
+    (
+        ":expand [path]",
+        "expand a node of the last response, or all of them",
+    ),
+    (
+        ":collapse [path]",
+        "collapse a node of the last response, or all of them",
+    ),
+    (":tree", "show the last response again"),
+    (
+        ":version <api> [version]",
+        "show or pin the version of an API",
+    ),
+    (":quit", "exit the shell"),
+];
+
+/// Line editor helper that completes the commands and API names.
+struct ShellHelper {
+    /// Names of the commands and their subcommands.
+    commands: Vec<(String, Vec<String>)>,
+}
+
+impl ShellHelper {
+    /// Creates a new helper with the commands of the tool.
+    fn new() -> Self {
+        let commands = Args::command()
+            .get_subcommands()
+            .map(|command| {
+                (
+                    command.get_name().to_string(),
+                    command
+                        .get_subcommands()
+                        .map(|subcommand| subcommand.get_name().to_string())
+                        .collect(),
+                )
+            })
+            .collect();
+        Self { commands }
+    }
+
+    /// Returns the candidates for the word at the given position of the words.
+    fn candidates(&self, previous: &[&str]) -> Vec<String> {
+        match previous {
+            [] => self
+                .commands
+                .iter()
+                .map(|(name, _)| name.to_string())
+                .chain(
+                    SHELL_COMMANDS
+                        .iter()
+                        .map(|(usage, _)| usage.split(' ').next().unwrap_or_default().to_string()),
+                )
+                .collect(),
+            [":version"] => API_TYPES
+                .iter()
+                .map(|api_type| api_name(*api_type).to_string())
+                .collect(),
+            [command] => self
+                .commands
+                .iter()
+                .find(|(name, _)| name == command)
+                .map(|(_, subcommands)| subcommands.clone())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl Completer for ShellHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let line = &line[..pos];
+        let start = line.rfind(char::is_whitespace).map_or(0, |index| index + 1);
+        let previous = line[..start].split_whitespace().collect::<Vec<&str>>();
+        let word = &line[start..];
+        let candidates = self
+            .candidates(&previous)
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(word))
+            .collect();
+        Ok((start, candidates))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+/// Interactive shell that keeps a client alive between the lookups.
+pub struct Shell {
+    /// Client that is used for the lookups.
+    abstractapi: AbstractApi,
+    /// Last successful response.
+    last: Option<Value>,
+    /// Tree view of the last response.
+    tree: Tree,
+}
+
+impl Shell {
+    /// Creates a new shell with the given client.
+    pub fn new(abstractapi: AbstractApi) -> Self {
+        Self {
+            abstractapi,
+            last: None,
+            tree: Tree::default(),
+        }
+    }
+
+    /// Runs the shell until it is exited.
+    ///
+    /// The history is kept in the config directory of the tool.
+    pub fn run(&mut self) -> Result<()> {
+        let mut editor = Editor::<ShellHelper, DefaultHistory>::new().map_err(readline_error)?;
+        editor.set_helper(Some(ShellHelper::new()));
+        let history_path = config::config_dir().map(|config_dir| config_dir.join("history"));
+        if let Some(history_path) = &history_path {
+            // The history does not exist on the first run.
+            let _ = editor.load_history(history_path);
+        }
+        println!("Abstract API shell, type :help for the commands.");
+        loop {
+            let line = match editor.readline("abstractapi> ") {
+                Ok(line) => line,
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(e) => return Err(readline_error(e)),
+            };
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            editor.add_history_entry(line).map_err(readline_error)?;
+            match self.handle(line) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e) => eprintln!("error: {e}"),
+            }
+        }
+        if let Some(history_path) = history_path {
+            if let Some(config_dir) = history_path.parent() {
+                fs::create_dir_all(config_dir)?;
+            }
+            editor.save_history(&history_path).map_err(readline_error)?;
+        }
+        Ok(())
+    }
+
+    /// Handles a line of input, returns `false` if the shell should exit.
+    fn handle(&mut self, line: &str) -> Result<bool> {
+        let words = shlex::split(line)
+            .ok_or_else(|| Error::InvalidOptions(String::from("unbalanced quotes")))?;
+        let command = match words.first() {
+            Some(command) => command.as_str(),
+            // Comments (e.g. `# note`) have no words.
+            None => return Ok(true),
+        };
+        let arg = words.get(1).map(String::as_str);
+        match command {
+            ":quit" | ":exit" | ":q" => return Ok(false),
+            ":help" => {
+                Args::command().print_help()?;
+                println!("\nShell commands:");
+                for (usage, description) in SHELL_COMMANDS {
+                    println!("  {usage:<28} {description}");
+                }
+            }
+            ":save" => {
+                let path = arg.ok_or_else(|| missing_argument("file"))?;
+                self.save(Path::new(path))?;
+                println!("Saved to {path}");
+            }
+            ":expand" => {
+                self.tree.expand(arg);
+                self.show_tree()?;
+            }
+            ":collapse" => {
+                self.tree.collapse(arg);
+                self.show_tree()?;
+            }
+            ":tree" => self.show_tree()?,
+            ":version" => {
+                let name = arg.ok_or_else(|| missing_argument("api"))?;
+                let api_type = api_type(name)
+                    .ok_or_else(|| Error::InvalidOptions(format!("unknown API: {name}")))?;
+                if let Some(version) = words.get(2) {
+                    let version = version.trim_start_matches('v').parse().map_err(|_| {
+                        Error::InvalidOptions(format!("invalid version: {version}"))
+                    })?;
+                    self.abstractapi
+                        .set_api_version(api_type, ApiVersion(version));
+                }
+                println!("{name}: {}", self.abstractapi.api_version(api_type));
+            }
+            command if command.starts_with(':') => {
+                return Err(Error::InvalidOptions(format!(
+                    "unknown command: {command}, type :help for the commands"
+                )));
+            }
+            _ => self.lookup(words)?,
+        }
+        Ok(true)
+    }
+
+    /// Runs a command of the tool and shows its result with the number of requests and latency.
+    ///
+    /// The number of requests approximates the consumed credits, since cached
+    /// and prechecked lookups do not send a request.
+    fn lookup(&mut self, words: Vec<String>) -> Result<()> {
+        let args = match Args::try_parse_from(iter::once(String::from("abstractapi")).chain(words))
+        {
+            Ok(args) => args,
+            Err(e) => {
+                e.print()?;
+                return Ok(());
+            }
+        };
+        let command = match &args.command {
+            Some(command) => command,
+            None => return Ok(()),
+        };
+        let request_count = self.abstractapi.request_count();
+        let start = Instant::now();
+        let stdout = io::stdout();
+        let mut writer = stdout.lock();
+        let result = match &args.input {
+            Some(path) => {
+                // The batch records are not kept, so `:save` does not save a stale response.
+                self.last = None;
+                self.tree = Tree::default();
+                let inputs = read_inputs(File::open(path)?, args.column.as_deref())?;
+                batch::run(
+                    &self.abstractapi,
+                    command,
+                    inputs,
+                    args.concurrency,
+                    &mut writer,
+                )
+            }
+            None => lookup(&self.abstractapi, command, None).and_then(|value| {
+                match args.format {
+                    Some(format) => output::write(&value, format, &mut writer)?,
+                    None => {
+                        self.tree = Tree::default();
+                        write!(writer, "{}", self.tree.render(&value))?;
+                    }
+                }
+                self.last = Some(value);
+                Ok(())
+            }),
+        };
+        let requests = self.abstractapi.request_count() - request_count;
+        writeln!(
+            writer,
+            "({requests} request{}, {} ms)",
+            if requests == 1 { "" } else { "s" },
+            start.elapsed().as_millis()
+        )?;
+        result
+    }
+
+    /// Shows the tree of the last response.
+    fn show_tree(&self) -> Result<()> {
+        let last = self.last.as_ref().ok_or_else(no_response)?;
+        print!("{}", self.tree.render(last));
+        Ok(())
+    }
+
+    /// Saves the last response as JSON or CSV, depending on the file extension.
+    fn save(&self, path: &Path) -> Result<()> {
+        let last = self.last.as_ref().ok_or_else(no_response)?;
+        let format = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Format::Json,
+            Some("csv") => Format::Csv,
+            _ => {
+                return Err(Error::InvalidOptions(String::from(
+                    "the file extension should be .json or .csv",
+                )))
+            }
+        };
+        output::write(last, format, &mut File::create(path)?)
+    }
+}
+
+/// Returns the error of a shell command that needs a response.
+fn no_response() -> Error {
+    Error::InvalidOptions(String::from("there is no response yet"))
+}
+
+/// Converts a line editor error into an IO error.
+fn readline_error(error: ReadlineError) -> Error {
+    Error::IoError(io::Error::other(error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_complete() -> rustyline::Result<()> {
+        let helper = ShellHelper::new();
+        let history = DefaultHistory::new();
+        let context = Context::new(&history);
+        assert_eq!(
+            (0, vec![String::from("vat")]),
+            helper.complete("v", 1, &context)?
+        );
+        assert_eq!(
+            (0, vec![String::from(":save")]),
+            helper.complete(":s", 2, &context)?
+        );
+        assert_eq!(
+            (6, vec![String::from("live")]),
+            helper.complete("rates l", 7, &context)?
+        );
+        assert_eq!(
+            (
+                9,
+                vec![
+                    String::from("email_validation"),
+                    String::from("email_reputation")
+                ]
+            ),
+            helper.complete(":version email", 14, &context)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_handle() -> Result<()> {
+        let mut shell = Shell::new(AbstractApi::default());
+        assert!(shell.handle("# note")?);
+        assert!(shell.handle(":save result.json").is_err());
+        let path = std::env::temp_dir().join(format!("abstractapi-shell-{}", std::process::id()));
+        fs::write(&path, "123\n")?;
+        shell.last = Some(Value::Null);
+        assert!(shell.handle(&format!("phone --input {}", path.display()))?);
+        assert_eq!(None, shell.last);
+        fs::remove_file(path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Tree view of a JSON response with collapsible nodes.
+///
+/// Nodes are addressed by their dotted paths, like the flattened fields of
+/// the table output (e.g. `timezone` or `rates.0`).
+#[derive(Debug, Default)]
+pub struct Tree {
+    /// Whether the nodes are expanded unless they are toggled.
+    expand_all: bool,
+    /// Nodes that are explicitly expanded (`true`) or collapsed (`false`).
+    toggled: HashMap<String, bool>,
+}
+
+impl Tree {
+    /// Expands the node at the given path, or all the nodes.
+    pub fn expand(&mut self, path: Option<&str>) {
+        match path {
+            Some(path) => {
+                self.toggled.insert(path.to_string(), true);
+            }
+            None => {
+                self.expand_all = true;
+                self.toggled.clear();
+            }
+        }
+    }
+
+    /// Collapses the node at the given path, or all the nodes.
+    pub fn collapse(&mut self, path: Option<&str>) {
+        match path {
+            Some(path) => {
+                self.toggled.insert(path.to_string(), false);
+            }
+            None => {
+                self.expand_all = false;
+                self.toggled.clear();
+            }
+        }
+    }
+
+    /// Renders the value, the nested nodes are collapsed by default.
+    pub fn render(&self, value: &Value) -> String {
+        let mut output = String::new();
+        match value {
+            Value::Object(_) | Value::Array(_) => self.render_children(value, "", 0, &mut output),
+            value => output.push_str(&format!("{value}\n")),
+        }
+        output
+    }
+
+    /// Renders the children of a node with the given indentation level.
+    fn render_children(&self, value: &Value, path: &str, level: usize, output: &mut String) {
+        let children: Vec<(String, &Value)> = match value {
+            Value::Object(map) => map
+                .iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+            Value::Array(values) => values
+                .iter()
+                .enumerate()
+                .map(|(index, value)| (index.to_string(), value))
+                .collect(),
+            _ => Vec::new(),
+        };
+        let indent = "  ".repeat(level);
+        for (key, value) in children {
+            let child_path = if path.is_empty() {
+                key.to_string()
+            } else {
+                format!("{path}.{key}")
+            };
+            let size = match value {
+                Value::Object(map) if !map.is_empty() => format!("{{{} fields}}", map.len()),
+                Value::Array(values) if !values.is_empty() => format!("[{} items]", values.len()),
+                value => {
+                    output.push_str(&format!("{indent}  {key}: {value}\n"));
+                    continue;
+                }
+            };
+            let is_expanded = self
+                .toggled
+                .get(&child_path)
+                .copied()
+                .unwrap_or(self.expand_all);
+            if is_expanded {
+                output.push_str(&format!("{indent}▾ {key}\n"));
+                self.render_children(value, &child_path, level + 1, output);
+            } else {
+                output.push_str(&format!("{indent}▸ {key} {size}\n"));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn test_render() {
+        let value =
+            json!({"ip": "1.2.3.4", "country": {"code": "FR", "tags": [1, 2]}, "flags": []});
+        let mut tree = Tree::default();
+        assert_eq!(
+            "  ip: \"1.2.3.4\"\n▸ country {2 fields}\n  flags: []\n",
+            tree.render(&value)
+        );
+        tree.expand(Some("country"));
+        assert_eq!(
+            "  ip: \"1.2.3.4\"\n▾ country\n    code: \"FR\"\n  ▸ tags [2 items]\n  flags: []\n",
+            tree.render(&value)
+        );
+        tree.expand(None);
+        tree.collapse(Some("country.tags"));
+        assert!(tree.render(&value).contains("  ▸ tags [2 items]\n"));
+        tree.expand(Some("country.tags"));
+        assert!(tree.render(&value).contains("      0: 1\n      1: 2\n"));
+    }
+}
@@ -0,0 +1,42 @@
+use dashmap::DashMap;
+use std::time::{Duration, Instant};
+
+/// In-memory cache of response bodies, keyed by the request URL.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    ttl: Duration,
+    entries: DashMap<String, (Instant, String)>,
+}
+
+impl ResponseCache {
+    /// Creates a new cache that keeps the responses for the given duration.
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: DashMap::new(),
+        }
+    }
+
+    /// Returns the cached response body for the given key if it is not expired.
+    pub(crate) fn get(&self, key: &str) -> Option<String> {
+        let entry = self.entries.get(key)?;
+        let (cached_at, body) = entry.value();
+        if cached_at.elapsed() < self.ttl {
+            Some(body.to_string())
+        } else {
+            drop(entry);
+            self.entries.remove(key);
+            None
+        }
+    }
+
+    /// Caches the response body for the given key.
+    pub(crate) fn insert(&self, key: String, body: String) {
+        self.entries.insert(key, (Instant::now(), body));
+    }
+
+    /// Removes all cached responses.
+    pub(crate) fn clear(&self) {
+        self.entries.clear();
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::ApiType;
 use lazy_regex::*;
+use std::fmt;
 
 /// Regex for matching the API key from an error response.
 static API_KEY_REGEX: Lazy<Regex> = lazy_regex!("api_key=[a-zA-Z0-9]{32,}");
@@ -19,19 +20,118 @@ thiserror_lite::err_enum! {
         // Error that may occur when handling a request.
         #[error("Request error: `{0}`")]
         RequestError(String),
+        // Error that may occur when the API returns an error status.
+        #[error("HTTP status error: `{0}`")]
+        HttpStatus(HttpStatus),
         // Error that may occur while handling IO operations.
         #[error("IO error: `{0}`")]
-        IoError(#[from] std::io::Error)
+        IoError(#[from] std::io::Error),
+        // Error that may occur when a currency is not present in the exchange rates.
+        #[error("Unknown currency: `{0}`")]
+        UnknownCurrency(String),
+        // Error that may occur when parsing an invalid date.
+        #[error("Invalid date: `{0}`")]
+        InvalidDate(String),
+        // Error that may occur when parsing an unknown timezone.
+        #[error("Invalid timezone: `{0}`")]
+        InvalidTimezone(String),
+        // Error that may occur when the company lookup is not a valid domain or email.
+        #[error("Invalid company lookup: `{0}`")]
+        InvalidCompanyLookup(String),
+        // Error that may occur when the phone number is malformed.
+        #[error("Invalid phone number: `{0}`")]
+        InvalidPhoneNumber(String),
+        // Error that may occur when the VAT number is malformed.
+        #[error("Invalid VAT number: `{0}`")]
+        InvalidVatNumber(String),
+        // Error that may occur when the IBAN is malformed.
+        #[error("Invalid IBAN: `{0}`")]
+        InvalidIban(String),
+        // Error that may occur when the request options are invalid.
+        #[error("Invalid options: `{0}`")]
+        InvalidOptions(String),
+        // Error that may occur when a method does not support the pinned API version.
+        #[error("Unsupported API version: `{0}`")]
+        UnsupportedApiVersion(String),
+        // Error that may occur when the VAT rate of a country is not available.
+        #[error("Missing VAT rate: `{0}`")]
+        MissingVatRate(String),
+        // Error that may occur when loading an invalid policy.
+        #[error("Invalid policy: `{0}`")]
+        InvalidPolicy(String),
+        // Error that may occur while reading or writing CSV.
+        #[error("CSV error: `{0}`")]
+        CsvError(#[from] csv::Error),
+        // Error that may occur when a CSV column is not present.
+        #[error("Column is not present: `{0}`")]
+        MissingColumn(String)
     }
 }
 
+/// Error status that is returned by the API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpStatus {
+    /// HTTP status code.
+    pub code: u16,
+    /// Description of the response, without the API key.
+    pub message: String,
+}
+
+impl fmt::Display for HttpStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Padding respects the precision, which hides the field in the error message.
+        f.pad(&format!("{}: {}", self.code, self.message))
+    }
+}
+
+/// Replaces the API key in the given text.
+fn hide_api_key(text: &str) -> String {
+    API_KEY_REGEX.replace(text, "api_key=***").to_string()
+}
+
 impl From<ureq::Error> for Error {
     fn from(error: ureq::Error) -> Self {
-        Self::RequestError(
-            API_KEY_REGEX
-                .replace(&format!("{error:?}"), "api_key=***")
-                .to_string(),
-        )
+        match error {
+            ureq::Error::Status(code, response) => Self::HttpStatus(HttpStatus {
+                code,
+                message: hide_api_key(&format!("{response:?}")),
+            }),
+            error => Self::RequestError(hide_api_key(&format!("{error:?}"))),
+        }
+    }
+}
+
+impl Error {
+    /// Returns the HTTP status code of the response if the API returned an error.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::HttpStatus(status) => Some(status.code),
+            _ => None,
+        }
+    }
+
+    /// Returns the kind of the error, which is the name of its variant.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ApiKeySetError => "ApiKeySetError",
+            Self::ApiKeyNotPresent(_) => "ApiKeyNotPresent",
+            Self::RequestError(_) => "RequestError",
+            Self::HttpStatus(_) => "HttpStatus",
+            Self::IoError(_) => "IoError",
+            Self::UnknownCurrency(_) => "UnknownCurrency",
+            Self::InvalidDate(_) => "InvalidDate",
+            Self::InvalidTimezone(_) => "InvalidTimezone",
+            Self::InvalidCompanyLookup(_) => "InvalidCompanyLookup",
+            Self::InvalidPhoneNumber(_) => "InvalidPhoneNumber",
+            Self::InvalidVatNumber(_) => "InvalidVatNumber",
+            Self::InvalidIban(_) => "InvalidIban",
+            Self::InvalidOptions(_) => "InvalidOptions",
+            Self::UnsupportedApiVersion(_) => "UnsupportedApiVersion",
+            Self::MissingVatRate(_) => "MissingVatRate",
+            Self::InvalidPolicy(_) => "InvalidPolicy",
+            Self::CsvError(_) => "CsvError",
+            Self::MissingColumn(_) => "MissingColumn",
+        }
     }
 }
 
@@ -42,12 +142,28 @@ pub(crate) type Result<T> = std::result::Result<T, Error>;
 mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_status_code() {
+        let error = Error::HttpStatus(HttpStatus {
+            code: 404,
+            message: String::from("Not Found"),
+        });
+        assert_eq!(Some(404), error.status_code());
+        assert_eq!("HttpStatus", error.kind());
+        assert_eq!("HTTP status error: `404: Not Found`", error.to_string());
+        assert_eq!(
+            None,
+            Error::RequestError(String::from("Status(404, ...)")).status_code()
+        );
+    }
+
     #[test]
     fn test_hide_api_key() {
         let mock_url = "https://emailvalidation.abstractapi.com/v1/?api_key=ef0482afc956e2ede15ed2d4b7c9c01e&email=test%40gmial.com&auto_correct=false";
         let ureq_error = ureq::get(mock_url).call().unwrap_err();
         let error = Error::from(ureq_error);
-        assert_eq!("RequestError(\"Status(401, Response[status: 401, status_text: Unauthorized, \
-            url: https://emailvalidation.abstractapi.com/v1/?api_key=***&email=test%40gmial.com&auto_correct=false])\")", format!("{error:?}"));
+        assert_eq!("HttpStatus(HttpStatus { code: 401, message: \"Response[status: 401, status_text: Unauthorized, \
+            url: https://emailvalidation.abstractapi.com/v1/?api_key=***&email=test%40gmial.com&auto_correct=false]\" })", format!("{error:?}"));
     }
 }
@@ -4,23 +4,40 @@
 
 /// API bindings.
 pub mod api;
+/// Batch processing of lookups.
+pub mod batch;
 /// Error implementation.
 pub mod error;
+/// Acceptance policies for API results.
+pub mod policy;
 /// Common types that can be glob-imported for convenience.
 pub mod prelude;
+/// Rounding of monetary amounts.
+pub mod rounding;
+
+mod cache;
+mod multipart;
+mod rate_limit;
 
 use api::*;
+use cache::ResponseCache;
 use dashmap::DashMap;
 use error::{Error, Result};
+use multipart::Multipart;
+use rate_limit::RateLimiter;
+use rounding::RoundingMode;
+use serde::de::DeserializeOwned;
 use std::fmt;
+use std::io::{self, Read, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
-use ureq::{Agent as HttpClient, AgentBuilder, Request};
+use ureq::{Agent as HttpClient, AgentBuilder, Request, Response};
 
 /// Base domain for Abstract API.
 const ABSTRACTAPI_DOMAIN: &str = "abstractapi.com";
 
 /// A supported API which is in free/paid plan.
-#[derive(Debug, Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum ApiType {
     /// Geolocation API.
     Geolocation,
@@ -38,6 +55,20 @@ pub enum ApiType {
     PhoneValidation,
     /// VAT API.
     Vat,
+    /// IBAN validation API.
+    IbanValidation,
+    /// Website screenshot API.
+    WebsiteScreenshot,
+    /// Web scraping API.
+    WebScraping,
+    /// Image processing API.
+    ImageProcessing,
+    /// Avatars API.
+    Avatars,
+    /// IP intelligence API.
+    IpIntelligence,
+    /// Email reputation API.
+    EmailReputation,
 }
 
 impl fmt::Display for ApiType {
@@ -54,15 +85,63 @@ impl fmt::Display for ApiType {
                 Self::EmailValidation => "emailvalidation",
                 Self::PhoneValidation => "phonevalidation",
                 Self::Vat => "vat",
+                Self::IbanValidation => "ibanvalidation",
+                Self::WebsiteScreenshot => "screenshot",
+                Self::WebScraping => "scrape",
+                Self::ImageProcessing => "images",
+                Self::Avatars => "avatars",
+                Self::IpIntelligence => "ip-intelligence",
+                Self::EmailReputation => "emailreputation",
             }
         )
     }
 }
 
+/// Version of an API endpoint (e.g. `v1`).
+///
+/// Versions are plain numbers so that new upstream versions can be used
+/// before this library provides typed responses for them.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub struct ApiVersion(pub u16);
+
+impl ApiVersion {
+    /// First version of the APIs.
+    pub const V1: Self = Self(1);
+    /// Second version of the APIs.
+    pub const V2: Self = Self(2);
+}
+
+impl Default for ApiVersion {
+    fn default() -> Self {
+        Self::V1
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{}", self.0)
+    }
+}
+
+/// Body of an HTTP request.
+enum RequestBody {
+    /// Request without a body.
+    Empty,
+    /// JSON body.
+    Json(serde_json::Value),
+    /// Body with the given content type.
+    Bytes(String, Vec<u8>),
+}
+
 /// Client for Abstract API.
 pub struct AbstractApi {
     http_client: HttpClient,
     api_keys: DashMap<ApiType, String>,
+    api_versions: DashMap<ApiType, ApiVersion>,
+    rate_limiter: Option<RateLimiter>,
+    cache: Option<ResponseCache>,
+    email_precheck: Option<EmailPrecheck>,
+    request_count: AtomicU64,
 }
 
 impl Default for AbstractApi {
@@ -87,6 +166,11 @@ impl AbstractApi {
         Self {
             http_client,
             api_keys: DashMap::new(),
+            api_versions: DashMap::new(),
+            rate_limiter: None,
+            cache: None,
+            email_precheck: None,
+            request_count: AtomicU64::new(0),
         }
     }
 
@@ -120,28 +204,230 @@ impl AbstractApi {
         Ok(())
     }
 
-    /// Constructs and returns an HTTP request for an API.
+    /// Pins the version of an API, which defaults to [`ApiVersion::V1`].
+    ///
+    /// The typed methods call the endpoint of the pinned version if they support it
+    /// (e.g. [`AbstractApi::validate_email`] supports v1 and v2), and fail with
+    /// [`Error::UnsupportedApiVersion`] otherwise. The versioned responses can be
+    /// requested with [`AbstractApi::call_api`] and the versioned response modules
+    /// (e.g. [`api::email_validation::v2`]).
+    pub fn set_api_version(&mut self, api_type: ApiType, api_version: ApiVersion) {
+        self.api_versions.insert(api_type, api_version);
+    }
+
+    /// Returns the pinned version of an API.
+    pub fn api_version(&self, api_type: ApiType) -> ApiVersion {
+        self.api_versions
+            .get(&api_type)
+            .map(|api_version| *api_version.value())
+            .unwrap_or_default()
+    }
+
+    /// Sets the minimum interval between consecutive requests.
+    ///
+    /// The limit is shared by all the APIs and threads that use this client.
+    pub fn set_rate_limit(&mut self, interval: Duration) {
+        self.rate_limiter = Some(RateLimiter::new(interval));
+    }
+
+    /// Enables caching of the successful responses for the given duration.
+    ///
+    /// Responses are cached per request URL, including the live endpoints.
+    pub fn set_cache_ttl(&mut self, ttl: Duration) {
+        self.cache = Some(ResponseCache::new(ttl));
+    }
+
+    /// Removes all the cached responses.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+
+    /// Returns the number of successful requests that are sent by this client.
+    ///
+    /// Each successful request consumes an API credit, while cached responses,
+    /// local prechecks and failed requests do not.
+    pub fn request_count(&self) -> u64 {
+        self.request_count.load(Ordering::Relaxed)
+    }
+
+    /// Enables checking the email addresses locally before validating them with the API.
+    ///
+    /// Addresses that fail the check are not sent to the API, see [`EmailPrecheck`].
+    pub fn set_email_precheck(&mut self, email_precheck: EmailPrecheck) {
+        self.email_precheck = Some(email_precheck);
+    }
+
+    /// Returns the API key of an API.
+    fn get_api_key(&self, api_type: ApiType) -> Result<String> {
+        Ok(self
+            .api_keys
+            .get(&api_type)
+            .ok_or(Error::ApiKeyNotPresent(api_type))?
+            .value()
+            .to_string())
+    }
+
+    /// Returns the pinned version of an API if it is one of the given versions.
+    fn check_api_version(&self, api_type: ApiType, supported: &[ApiVersion]) -> Result<ApiVersion> {
+        let api_version = self.api_version(api_type);
+        if supported.contains(&api_version) {
+            Ok(api_version)
+        } else {
+            Err(Error::UnsupportedApiVersion(format!(
+                "{api_type}/{api_version}"
+            )))
+        }
+    }
+
+    /// Returns the URL of an API endpoint, `path` is relative to the version.
+    fn get_api_url(api_type: ApiType, api_version: ApiVersion, path: &str) -> String {
+        if path.is_empty() {
+            format!("https://{api_type}.{ABSTRACTAPI_DOMAIN}/{api_version}/")
+        } else {
+            format!("https://{api_type}.{ABSTRACTAPI_DOMAIN}/{api_version}/{path}/")
+        }
+    }
+
+    /// Constructs and returns an HTTP request for the v1 endpoint of an API.
+    ///
+    /// Fails if another version is pinned, since the typed responses are for v1.
     fn get_api_request(&self, api_type: ApiType, path: &str) -> Result<Request> {
+        let api_version = self.check_api_version(api_type, &[ApiVersion::V1])?;
+        self.get_versioned_api_request(api_type, api_version, path)
+    }
+
+    /// Constructs and returns an HTTP request for the given version of an API.
+    fn get_versioned_api_request(
+        &self,
+        api_type: ApiType,
+        api_version: ApiVersion,
+        path: &str,
+    ) -> Result<Request> {
+        let api_key = self.get_api_key(api_type)?;
+        Ok(self
+            .http_client
+            .get(&Self::get_api_url(api_type, api_version, path))
+            .query("api_key", &api_key))
+    }
+
+    /// Constructs and returns an HTTP POST request for the v1 endpoint of an API.
+    ///
+    /// The API key is not added to the request since it is sent in the body.
+    fn post_api_request(&self, api_type: ApiType, path: &str) -> Result<Request> {
+        let api_version = self.check_api_version(api_type, &[ApiVersion::V1])?;
         Ok(self
             .http_client
-            .get(&format!("https://{api_type}.{ABSTRACTAPI_DOMAIN}/{path}/"))
-            .query(
-                "api_key",
-                self.api_keys
-                    .get(&api_type)
-                    .ok_or(Error::ApiKeyNotPresent(api_type))?
-                    .value(),
-            ))
+            .post(&Self::get_api_url(api_type, api_version, path)))
+    }
+
+    /// Sends the request and deserializes the JSON response.
+    ///
+    /// The request is delayed according to the rate limit and
+    /// served from the cache if caching is enabled.
+    fn call_json<T: DeserializeOwned>(&self, request: Request) -> Result<T> {
+        let cache_key = request.url().to_string();
+        if let Some(body) = self.cache.as_ref().and_then(|cache| cache.get(&cache_key)) {
+            return Ok(serde_json::from_str(&body).map_err(std::io::Error::from)?);
+        }
+        let body = self.send(request)?.into_string()?;
+        let value = serde_json::from_str(&body).map_err(std::io::Error::from)?;
+        if let Some(cache) = &self.cache {
+            cache.insert(cache_key, body);
+        }
+        Ok(value)
+    }
+
+    /// Sends the request and returns the response as text.
+    ///
+    /// Unlike JSON responses, text responses are not cached and not limited in size.
+    fn call_text(&self, request: Request) -> Result<String> {
+        let mut body = String::new();
+        self.send(request)?
+            .into_reader()
+            .read_to_string(&mut body)?;
+        Ok(body)
+    }
+
+    /// Sends the request and returns the binary response.
+    ///
+    /// Binary responses are not cached.
+    fn call_binary(&self, request: Request) -> Result<BinaryResponse> {
+        let response = self.send(request)?;
+        let content_type = response.content_type().to_string();
+        let mut data = Vec::new();
+        response.into_reader().read_to_end(&mut data)?;
+        Ok(BinaryResponse { content_type, data })
+    }
+
+    /// Sends the request, streams the binary response into the writer
+    /// and returns its content type.
+    fn call_writer<W: Write>(&self, request: Request, writer: &mut W) -> Result<String> {
+        let response = self.send(request)?;
+        let content_type = response.content_type().to_string();
+        io::copy(&mut response.into_reader(), writer)?;
+        Ok(content_type)
+    }
+
+    /// Sends the request with the body and deserializes the JSON response.
+    ///
+    /// Responses of requests with a body are not cached.
+    fn call_json_with_body<T: DeserializeOwned>(
+        &self,
+        request: Request,
+        body: RequestBody,
+    ) -> Result<T> {
+        let body = self.send_with_body(request, body)?.into_string()?;
+        Ok(serde_json::from_str(&body).map_err(std::io::Error::from)?)
+    }
+
+    /// Sends the request after waiting for the rate limit.
+    fn send(&self, request: Request) -> Result<Response> {
+        self.send_with_body(request, RequestBody::Empty)
+    }
+
+    /// Sends the request with the body after waiting for the rate limit.
+    fn send_with_body(&self, request: Request, body: RequestBody) -> Result<Response> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.wait();
+        }
+        let response = match body {
+            RequestBody::Empty => request.call(),
+            RequestBody::Json(value) => request.send_json(value),
+            RequestBody::Bytes(content_type, data) => {
+                request.set("Content-Type", &content_type).send_bytes(&data)
+            }
+        }
+        .map_err(Error::from)?;
+        self.request_count.fetch_add(1, Ordering::Relaxed);
+        Ok(response)
+    }
+
+    /// Calls an endpoint of the pinned API version and deserializes the JSON response.
+    ///
+    /// `path` is relative to the version (e.g. `live` for `v1/live`) and the
+    /// API key is added to the query. This allows using the versioned response
+    /// types, or custom types for versions that are not supported by this library.
+    pub fn call_api<T: DeserializeOwned, S: AsRef<str>>(
+        &self,
+        api_type: ApiType,
+        path: &str,
+        query: &[(S, S)],
+    ) -> Result<T> {
+        let request = query.iter().fold(
+            self.get_versioned_api_request(api_type, self.api_version(api_type), path)?,
+            |request, (key, value)| request.query(key.as_ref(), value.as_ref()),
+        );
+        self.call_json(request)
     }
 
     /// Upstream documentation: <https://app.abstractapi.com/api/ip-geolocation/documentation>
     pub fn get_geolocation<S: AsRef<str>>(&self, ip_address: S) -> Result<Geolocation> {
-        Ok(self
-            .get_api_request(ApiType::Geolocation, "v1")?
-            .query("ip_address", ip_address.as_ref())
-            .call()
-            .map_err(Error::from)?
-            .into_json()?)
+        let request = self
+            .get_api_request(ApiType::Geolocation, "")?
+            .query("ip_address", ip_address.as_ref());
+        self.call_json(request)
     }
 
     /// Upstream documentation: <https://app.abstractapi.com/api/holidays/documentation>
@@ -152,15 +438,13 @@ impl AbstractApi {
         month: S,
         day: S,
     ) -> Result<Holidays> {
-        Ok(self
-            .get_api_request(ApiType::Holidays, "v1")?
+        let request = self
+            .get_api_request(ApiType::Holidays, "")?
             .query("country", country.as_ref())
             .query("year", year.as_ref())
             .query("month", month.as_ref())
-            .query("day", day.as_ref())
-            .call()
-            .map_err(Error::from)?
-            .into_json()?)
+            .query("day", day.as_ref());
+        self.call_json(request)
     }
 
     /// Upstream documentation: <https://app.abstractapi.com/api/exchange-rates/documentation>
@@ -170,12 +454,12 @@ impl AbstractApi {
         target: Option<S>,
     ) -> Result<ExchangeRatesResult> {
         let mut request = self
-            .get_api_request(ApiType::ExchangeRates, "v1/live")?
+            .get_api_request(ApiType::ExchangeRates, "live")?
             .query("base", base.as_ref());
         if let Some(target) = target {
             request = request.query("target", target.as_ref());
         }
-        Ok(request.call().map_err(Error::from)?.into_json()?)
+        self.call_json(request)
     }
 
     /// Upstream documentation: <https://app.abstractapi.com/api/exchange-rates/documentation>
@@ -186,13 +470,13 @@ impl AbstractApi {
         date: S,
     ) -> Result<ExchangeRatesResult> {
         let mut request = self
-            .get_api_request(ApiType::ExchangeRates, "v1/historical")?
+            .get_api_request(ApiType::ExchangeRates, "historical")?
             .query("base", base.as_ref())
             .query("date", date.as_ref());
         if let Some(target) = target {
             request = request.query("target", target.as_ref());
         }
-        Ok(request.call().map_err(Error::from)?.into_json()?)
+        self.call_json(request)
     }
 
     /// Upstream documentation: <https://app.abstractapi.com/api/exchange-rates/documentation>
@@ -204,7 +488,7 @@ impl AbstractApi {
         base_amount: Option<u64>,
     ) -> Result<ConvertedExchangeRate> {
         let mut request = self
-            .get_api_request(ApiType::ExchangeRates, "v1/convert")?
+            .get_api_request(ApiType::ExchangeRates, "convert")?
             .query("base", base.as_ref())
             .query("target", target.as_ref());
         if let Some(date) = date {
@@ -213,85 +497,134 @@ impl AbstractApi {
         if let Some(base_amount) = base_amount {
             request = request.query("base_amount", &base_amount.to_string());
         }
-        Ok(request.call().map_err(Error::from)?.into_json()?)
+        self.call_json(request)
     }
 
-    /// Upstream documentation: <https://app.abstractapi.com/api/company-enrichment/documentation>
-    pub fn get_company_details<S: AsRef<str>>(
+    /// Returns the daily exchange rates between the given dates (inclusive, `YYYY-MM-DD`).
+    ///
+    /// Historical rates are requested for each date, so it is recommended to
+    /// enable caching and rate limiting on the client. See [`GapPolicy`] for
+    /// the handling of weekends and dates with missing rates.
+    pub fn get_exchange_rate_series<S: AsRef<str>>(
         &self,
-        domain: Option<S>,
-        email: Option<S>,
-    ) -> Result<CompanyDetails> {
-        let mut request = self.get_api_request(ApiType::CompanyEnrichment, "v1")?;
-        if let Some(domain) = domain {
-            request = request.query("domain", domain.as_ref());
-        }
-        if let Some(email) = email {
-            request = request.query("email", email.as_ref());
-        }
-        Ok(request.call().map_err(Error::from)?.into_json()?)
+        base: S,
+        targets: &[S],
+        start: S,
+        end: S,
+        gap_policy: GapPolicy,
+    ) -> Result<ExchangeRateSeries> {
+        ExchangeRateSeries::build(
+            base.as_ref(),
+            targets.iter().map(AsRef::as_ref),
+            start.as_ref(),
+            end.as_ref(),
+            gap_policy,
+            |target, date| {
+                self.get_historical_exchange_rates(base.as_ref(), target, date)
+                    .map(|result| result.exchange_rates)
+            },
+        )
+    }
+
+    /// Upstream documentation: <https://app.abstractapi.com/api/company-enrichment/documentation>
+    ///
+    /// The lookup is normalized before the request is sent, see [`CompanyLookup::normalize`].
+    pub fn get_company_details(&self, lookup: CompanyLookup) -> Result<CompanyDetails> {
+        let request = self.get_api_request(ApiType::CompanyEnrichment, "")?;
+        let request = match lookup.normalize()? {
+            CompanyLookup::Domain(domain) => request.query("domain", &domain),
+            CompanyLookup::Email(email) => request.query("email", &email),
+        };
+        self.call_json(request)
     }
 
     /// Upstream documentation: <https://app.abstractapi.com/api/timezone/documentation>
     pub fn get_current_time<S: AsRef<str>>(&self, location: S) -> Result<LocationTime> {
-        Ok(self
-            .get_api_request(ApiType::Timezone, "v1/current_time")?
-            .query("location", location.as_ref())
-            .call()
-            .map_err(Error::from)?
-            .into_json()?)
+        let request = self
+            .get_api_request(ApiType::Timezone, "current_time")?
+            .query("location", location.as_ref());
+        self.call_json(request)
     }
 
     /// Upstream documentation: <https://app.abstractapi.com/api/timezone/documentation>
-    pub fn convert_time<S: AsRef<str>>(
+    ///
+    /// `base_datetime` is either a string in the `YYYY-MM-DD HH:MM:SS` format or
+    /// a `chrono::NaiveDateTime` if the `chrono` feature is enabled.
+    pub fn convert_time<S: AsRef<str>, D: ApiDateTime>(
         &self,
         base_location: S,
-        base_datetime: S,
+        base_datetime: D,
         target_location: S,
     ) -> Result<ConvertedTime> {
-        Ok(self
-            .get_api_request(ApiType::Timezone, "v1/convert_time")?
+        let request = self
+            .get_api_request(ApiType::Timezone, "convert_time")?
             .query("base_location", base_location.as_ref())
-            .query("base_datetime", base_datetime.as_ref())
-            .query("target_location", target_location.as_ref())
-            .call()
-            .map_err(Error::from)?
-            .into_json()?)
+            .query("base_datetime", &base_datetime.to_api_datetime())
+            .query("target_location", target_location.as_ref());
+        self.call_json(request)
     }
 
     /// Upstream documentation: <https://app.abstractapi.com/api/email-validation/documentation>
+    ///
+    /// Uses the pinned version of the API (v1 or v2). The v2 responses are
+    /// converted into the v1 details.
     pub fn validate_email<S: AsRef<str>>(
         &self,
         email: S,
         auto_correct: bool,
     ) -> Result<EmailDetails> {
-        Ok(self
-            .get_api_request(ApiType::EmailValidation, "v1")?
-            .query("email", email.as_ref())
-            .query("auto_correct", &auto_correct.to_string())
-            .call()
-            .map_err(Error::from)?
-            .into_json()?)
+        let api_version =
+            self.check_api_version(ApiType::EmailValidation, &[ApiVersion::V1, ApiVersion::V2])?;
+        if let Some(email_details) = self
+            .email_precheck
+            .as_ref()
+            .and_then(|email_precheck| email_precheck.check(email.as_ref()))
+        {
+            return Ok(email_details);
+        }
+        let request = self
+            .get_versioned_api_request(ApiType::EmailValidation, api_version, "")?
+            .query("email", email.as_ref());
+        if api_version == ApiVersion::V2 {
+            let mut email_details =
+                EmailDetails::from(self.call_json::<email_validation::v2::EmailDetails>(request)?);
+            if !auto_correct {
+                email_details.autocorrect = None;
+            }
+            Ok(email_details)
+        } else {
+            self.call_json(request.query("auto_correct", &auto_correct.to_string()))
+        }
     }
 
     /// Upstream documentation: <https://app.abstractapi.com/api/phone-validation/documentation>
-    pub fn validate_phone<S: AsRef<str>>(&self, phone: S) -> Result<PhoneDetails> {
-        Ok(self
-            .get_api_request(ApiType::PhoneValidation, "v1")?
-            .query("phone", phone.as_ref())
-            .call()
-            .map_err(Error::from)?
-            .into_json()?)
+    ///
+    /// The phone number is normalized before the request is sent, see [`normalize_phone_number`].
+    /// `country` is an optional ISO 3166-1 alpha-2 code for validating national numbers.
+    pub fn validate_phone<S: AsRef<str>>(
+        &self,
+        phone: S,
+        country: Option<S>,
+    ) -> Result<PhoneDetails> {
+        let phone = normalize_phone_number(phone)?;
+        let mut request = self
+            .get_api_request(ApiType::PhoneValidation, "")?
+            .query("phone", &phone);
+        if let Some(country) = country {
+            request = request.query("country", country.as_ref());
+        }
+        self.call_json(request)
     }
 
     /// Upstream documentation: <https://app.abstractapi.com/api/vat/documentation>
+    ///
+    /// The VAT number is validated locally before the request is sent, see [`normalize_vat_number`].
     pub fn validate_vat<S: AsRef<str>>(&self, vat_number: S) -> Result<VatDetails> {
-        Ok(self
-            .get_api_request(ApiType::Vat, "v1/validate")?
-            .query("vat_number", vat_number.as_ref())
-            .call()
-            .map_err(Error::from)?
-            .into_json()?)
+        let vat_number = normalize_vat_number(vat_number)?;
+        let request = self
+            .get_api_request(ApiType::Vat, "validate")?
+            .query("vat_number", &vat_number);
+        self.call_json(request)
     }
 
     /// Upstream documentation: <https://app.abstractapi.com/api/vat/documentation>
@@ -300,26 +633,257 @@ impl AbstractApi {
         amount: f64,
         country_code: S,
         is_vat_incl: bool,
-        vat_category: Option<S>,
+        vat_category: Option<VatCategory>,
     ) -> Result<Vat> {
         let mut request = self
-            .get_api_request(ApiType::Vat, "v1/calculate")?
+            .get_api_request(ApiType::Vat, "calculate")?
             .query("amount", &amount.to_string())
             .query("country_code", country_code.as_ref())
             .query("is_vat_incl", &is_vat_incl.to_string());
         if let Some(vat_category) = vat_category {
-            request = request.query("vat_category", vat_category.as_ref())
+            request = request.query("vat_category", &vat_category.to_string())
         }
-        Ok(request.call().map_err(Error::from)?.into_json()?)
+        self.call_json(request)
     }
 
     /// Upstream documentation: <https://app.abstractapi.com/api/vat/documentation>
     pub fn get_vat_rates<S: AsRef<str>>(&self, country_code: S) -> Result<VatRates> {
-        Ok(self
-            .get_api_request(ApiType::Vat, "v1/categories")?
-            .query("country_code", country_code.as_ref())
-            .call()
-            .map_err(Error::from)?
-            .into_json()?)
+        let request = self
+            .get_api_request(ApiType::Vat, "categories")?
+            .query("country_code", country_code.as_ref());
+        self.call_json(request)
+    }
+
+    /// Computes the VAT of an invoice by calling [`AbstractApi::calculate_vat`] for each line.
+    ///
+    /// The sums are rounded half up, see [`Invoice::calculate`] for computing the VAT
+    /// locally with the rounding mode of a [`VatTable`].
+    pub fn calculate_invoice_vat(&self, invoice: &Invoice) -> Result<InvoiceVat> {
+        invoice.build(RoundingMode::HalfUp, |amount, is_vat_incl, vat_category| {
+            self.calculate_vat(amount, invoice.country_code(), is_vat_incl, vat_category)
+        })
+    }
+
+    /// Determines the VAT of a sale to a customer who may be in another EU member state.
+    ///
+    /// The customer's country is taken from the VAT number if it is valid, then from
+    /// the billing country and then from the IP address. Business customers in another
+    /// member state are reverse charged and customers outside the EU are not charged.
+    /// Otherwise, the VAT of the customer's country is calculated by the API.
+    /// If none of them is available, the treatment is [`VatTreatment::InsufficientEvidence`]
+    /// without VAT, so the sale can be refused or reviewed with the collected evidence.
+    ///
+    /// This requires API keys for both [`ApiType::Geolocation`] and [`ApiType::Vat`].
+    pub fn determine_vat(&self, sale: &CrossBorderSale) -> Result<VatDetermination> {
+        let geolocation = self.get_geolocation(&sale.ip_address)?;
+        let vat_details = match &sale.vat_number {
+            Some(vat_number) => match self.validate_vat(vat_number) {
+                Ok(vat_details) => Some(vat_details),
+                Err(Error::InvalidVatNumber(_)) => None,
+                Err(e) => return Err(e),
+            },
+            None => None,
+        };
+        let evidence = VatEvidence::new(
+            &geolocation,
+            sale.billing_country.as_deref(),
+            sale.vat_number.as_deref(),
+            vat_details.as_ref(),
+        );
+        let treatment = evidence.treatment(&sale.seller_country);
+        let taxable_country = evidence.customer_country().unwrap_or_default().to_string();
+        let vat = match treatment {
+            VatTreatment::Domestic | VatTreatment::CustomerCountry => Some(self.calculate_vat(
+                sale.amount,
+                taxable_country.as_str(),
+                sale.is_vat_incl,
+                sale.vat_category.clone(),
+            )?),
+            VatTreatment::ReverseCharge | VatTreatment::OutsideScope => {
+                Some(sale.zero_vat(&taxable_country))
+            }
+            VatTreatment::InsufficientEvidence => None,
+        };
+        Ok(VatDetermination {
+            taxable_country,
+            treatment,
+            vat,
+            evidence,
+        })
+    }
+
+    /// Returns a [`VatTable`] with the VAT rates of the given countries.
+    pub fn get_vat_table<S: AsRef<str>>(&self, country_codes: &[S]) -> Result<VatTable> {
+        let mut vat_table = VatTable::new();
+        for country_code in country_codes {
+            vat_table.insert(country_code, self.get_vat_rates(country_code)?);
+        }
+        Ok(vat_table)
+    }
+
+    /// Fetches the VAT rates of the countries that are stale in the given table.
+    pub fn refresh_vat_table(&self, vat_table: &mut VatTable) -> Result<()> {
+        for country_code in vat_table.stale_countries() {
+            let vat_rates = self.get_vat_rates(&country_code)?;
+            vat_table.insert(country_code, vat_rates);
+        }
+        Ok(())
+    }
+
+    /// Upstream documentation: <https://app.abstractapi.com/api/iban-validation/documentation>
+    ///
+    /// The IBAN is validated locally before the request is sent, see [`normalize_iban`].
+    pub fn validate_iban<S: AsRef<str>>(&self, iban: S) -> Result<IbanDetails> {
+        let iban = normalize_iban(iban)?;
+        let request = self
+            .get_api_request(ApiType::IbanValidation, "")?
+            .query("iban", &iban);
+        self.call_json(request)
+    }
+
+    /// Upstream documentation: <https://app.abstractapi.com/api/screenshot/documentation>
+    pub fn capture_screenshot<S: AsRef<str>>(
+        &self,
+        url: S,
+        options: &ScreenshotOptions,
+    ) -> Result<BinaryResponse> {
+        let request = self.get_screenshot_request(url.as_ref(), options)?;
+        self.call_binary(request)
+    }
+
+    /// Captures a screenshot and streams the image into the writer.
+    ///
+    /// Returns the content type of the image.
+    pub fn capture_screenshot_to<S: AsRef<str>, W: Write>(
+        &self,
+        url: S,
+        options: &ScreenshotOptions,
+        writer: &mut W,
+    ) -> Result<String> {
+        let request = self.get_screenshot_request(url.as_ref(), options)?;
+        self.call_writer(request, writer)
+    }
+
+    /// Constructs the request for capturing a screenshot.
+    fn get_screenshot_request(&self, url: &str, options: &ScreenshotOptions) -> Result<Request> {
+        Ok(options.query_pairs().into_iter().fold(
+            self.get_api_request(ApiType::WebsiteScreenshot, "")?
+                .query("url", url),
+            |request, (key, value)| request.query(key, &value),
+        ))
+    }
+
+    /// Upstream documentation: <https://app.abstractapi.com/api/scrape/documentation>
+    pub fn scrape<S: AsRef<str>>(&self, url: S, options: &ScrapeOptions) -> Result<String> {
+        let request = self.get_scrape_request(url.as_ref(), options)?;
+        self.call_text(request)
+    }
+
+    /// Scrapes a page and streams its contents into the writer.
+    ///
+    /// Returns the content type of the page.
+    pub fn scrape_to<S: AsRef<str>, W: Write>(
+        &self,
+        url: S,
+        options: &ScrapeOptions,
+        writer: &mut W,
+    ) -> Result<String> {
+        let request = self.get_scrape_request(url.as_ref(), options)?;
+        self.call_writer(request, writer)
+    }
+
+    /// Constructs the request for scraping a page.
+    fn get_scrape_request(&self, url: &str, options: &ScrapeOptions) -> Result<Request> {
+        let request = options.query_pairs().into_iter().fold(
+            self.get_api_request(ApiType::WebScraping, "")?
+                .query("url", url),
+            |request, (key, value)| request.query(key, &value),
+        );
+        // The headers are forwarded to the scraped page.
+        Ok(options
+            .headers
+            .iter()
+            .fold(request, |request, (name, value)| request.set(name, value)))
+    }
+
+    /// Upstream documentation: <https://app.abstractapi.com/api/images/documentation>
+    ///
+    /// The options are validated locally before the request is sent.
+    pub fn process_image(
+        &self,
+        source: &ImageSource,
+        options: &ImageOptions,
+    ) -> Result<ProcessedImage> {
+        options.validate()?;
+        let api_key = self.get_api_key(ApiType::ImageProcessing)?;
+        match source {
+            ImageSource::Url(url) => {
+                let request = self.post_api_request(ApiType::ImageProcessing, "url")?;
+                let body = options.to_json(&api_key, Some(url))?;
+                self.call_json_with_body(request, RequestBody::Json(body))
+            }
+            ImageSource::Upload { file_name, data } => {
+                let request = self.post_api_request(ApiType::ImageProcessing, "upload")?;
+                let mut multipart = Multipart::new();
+                multipart.add_file("image", file_name, data);
+                multipart.add_text("data", &options.to_json(&api_key, None)?.to_string());
+                let (content_type, body) = multipart.finish();
+                self.call_json_with_body(request, RequestBody::Bytes(content_type, body))
+            }
+        }
+    }
+
+    /// Upstream documentation: <https://app.abstractapi.com/api/avatars/documentation>
+    ///
+    /// The options are validated locally before the request is sent.
+    pub fn generate_avatar<S: AsRef<str>>(
+        &self,
+        name: S,
+        options: &AvatarOptions,
+    ) -> Result<BinaryResponse> {
+        let request = self.get_avatar_request(name.as_ref(), options)?;
+        self.call_binary(request)
+    }
+
+    /// Generates an avatar and streams the image into the writer.
+    ///
+    /// Returns the content type of the image.
+    pub fn generate_avatar_to<S: AsRef<str>, W: Write>(
+        &self,
+        name: S,
+        options: &AvatarOptions,
+        writer: &mut W,
+    ) -> Result<String> {
+        let request = self.get_avatar_request(name.as_ref(), options)?;
+        self.call_writer(request, writer)
+    }
+
+    /// Constructs the request for generating an avatar.
+    fn get_avatar_request(&self, name: &str, options: &AvatarOptions) -> Result<Request> {
+        if name.trim().is_empty() {
+            return Err(Error::InvalidOptions(String::from("name is empty")));
+        }
+        options.validate()?;
+        Ok(options.query_pairs().into_iter().fold(
+            self.get_api_request(ApiType::Avatars, "")?
+                .query("name", name),
+            |request, (key, value)| request.query(key, &value),
+        ))
+    }
+
+    /// Upstream documentation: <https://app.abstractapi.com/api/ip-intelligence/documentation>
+    pub fn get_ip_intelligence<S: AsRef<str>>(&self, ip_address: S) -> Result<IpIntelligence> {
+        let request = self
+            .get_api_request(ApiType::IpIntelligence, "")?
+            .query("ip_address", ip_address.as_ref());
+        self.call_json(request)
+    }
+
+    /// Upstream documentation: <https://app.abstractapi.com/api/email-reputation/documentation>
+    pub fn get_email_reputation<S: AsRef<str>>(&self, email: S) -> Result<EmailReputation> {
+        let request = self
+            .get_api_request(ApiType::EmailReputation, "")?
+            .query("email", email.as_ref());
+        self.call_json(request)
     }
 }
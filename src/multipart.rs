@@ -0,0 +1,88 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Encoder of `multipart/form-data` request bodies.
+#[derive(Debug)]
+pub(crate) struct Multipart {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl Multipart {
+    /// Creates a new empty body with a unique boundary.
+    pub(crate) fn new() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        Self {
+            boundary: format!("abstractapi-{:x}-{nanos:x}", std::process::id()),
+            body: Vec::new(),
+        }
+    }
+
+    /// Adds a text field.
+    pub(crate) fn add_text(&mut self, name: &str, value: &str) {
+        self.add_part(
+            &format!("Content-Disposition: form-data; name=\"{name}\""),
+            value.as_bytes(),
+        );
+    }
+
+    /// Adds a file field.
+    pub(crate) fn add_file(&mut self, name: &str, file_name: &str, data: &[u8]) {
+        let file_name = file_name.replace(['"', '\r', '\n'], "_");
+        self.add_part(
+            &format!(
+                "Content-Disposition: form-data; name=\"{name}\"; filename=\"{file_name}\"\r\n\
+                Content-Type: application/octet-stream"
+            ),
+            data,
+        );
+    }
+
+    /// Finishes the body and returns its content type and contents.
+    pub(crate) fn finish(mut self) -> (String, Vec<u8>) {
+        self.body
+            .extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        (
+            format!("multipart/form-data; boundary={}", self.boundary),
+            self.body,
+        )
+    }
+
+    /// Adds a part with the given headers.
+    fn add_part(&mut self, headers: &str, data: &[u8]) {
+        self.body
+            .extend_from_slice(format!("--{}\r\n{headers}\r\n\r\n", self.boundary).as_bytes());
+        self.body.extend_from_slice(data);
+        self.body.extend_from_slice(b"\r\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_multipart() {
+        let mut multipart = Multipart::new();
+        let boundary = multipart.boundary.clone();
+        multipart.add_text("data", "{}");
+        multipart.add_file("image", "cat\".png", b"PNG");
+        let (content_type, body) = multipart.finish();
+        assert_eq!(
+            format!("multipart/form-data; boundary={boundary}"),
+            content_type
+        );
+        assert_eq!(
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"data\"\r\n\r\n{{}}\r\n\
+                --{boundary}\r\nContent-Disposition: form-data; name=\"image\"; \
+                filename=\"cat_.png\"\r\nContent-Type: application/octet-stream\r\n\r\nPNG\r\n\
+                --{boundary}--\r\n"
+            ),
+            String::from_utf8_lossy(&body)
+        );
+    }
+}
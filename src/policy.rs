@@ -0,0 +1,239 @@
+use crate::api::{Deliverability, EmailDetails, Geolocation, LineType, PhoneDetails};
+#[cfg(feature = "toml")]
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+
+/// Decision of a policy, ordered by severity.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Decision {
+    /// The subject is accepted.
+    #[default]
+    Accept,
+    /// The subject needs a manual review.
+    Review,
+    /// The subject is rejected.
+    Reject,
+}
+
+/// Condition of a policy rule.
+///
+/// Conditions only match the results of the API they are about,
+/// e.g. [`Condition::Vpn`] never matches an [`EmailDetails`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Condition {
+    /// Email address has an invalid format.
+    InvalidEmailFormat,
+    /// Email address is from a disposable email provider.
+    DisposableEmail,
+    /// Email address is from a free email provider.
+    FreeEmail,
+    /// Email address is a role account (e.g. `team@`).
+    RoleEmail,
+    /// Email domain accepts all the addresses.
+    CatchallEmail,
+    /// Email address has one of the given deliverability statuses.
+    EmailDeliverability(Vec<Deliverability>),
+    /// Email address has a quality score below the given threshold.
+    EmailQualityBelow(f32),
+    /// Phone number is invalid.
+    InvalidPhone,
+    /// Phone number has one of the given line types.
+    PhoneLineType(Vec<LineType>),
+    /// Phone number is from one of the given countries (ISO 3166-1 alpha-2 codes).
+    PhoneCountry(Vec<String>),
+    /// IP address belongs to a VPN.
+    Vpn,
+    /// IP address is located in one of the given countries (ISO 3166-1 alpha-2 codes).
+    IpCountry(Vec<String>),
+}
+
+/// API result that can be evaluated by a [`Policy`].
+pub trait PolicySubject {
+    /// Returns whether the result matches the condition.
+    fn matches(&self, condition: &Condition) -> bool;
+}
+
+impl PolicySubject for EmailDetails {
+    fn matches(&self, condition: &Condition) -> bool {
+        match condition {
+            Condition::InvalidEmailFormat => !self.is_valid_format.value,
+            Condition::DisposableEmail => self.is_disposable_email.value,
+            Condition::FreeEmail => self.is_free_email.value,
+            Condition::RoleEmail => self.is_role_email.value,
+            Condition::CatchallEmail => self.is_catchall_email.value,
+            Condition::EmailDeliverability(values) => values.contains(&self.deliverability),
+            Condition::EmailQualityBelow(threshold) => self.quality_score < *threshold,
+            _ => false,
+        }
+    }
+}
+
+impl PolicySubject for PhoneDetails {
+    fn matches(&self, condition: &Condition) -> bool {
+        match condition {
+            Condition::InvalidPhone => !self.valid,
+            Condition::PhoneLineType(values) => values.contains(&self.type_),
+            Condition::PhoneCountry(codes) => codes
+                .iter()
+                .any(|code| code.eq_ignore_ascii_case(&self.country.code)),
+            _ => false,
+        }
+    }
+}
+
+impl PolicySubject for Geolocation {
+    fn matches(&self, condition: &Condition) -> bool {
+        match condition {
+            Condition::Vpn => self.security.is_vpn,
+            Condition::IpCountry(codes) => codes
+                .iter()
+                .any(|code| code.eq_ignore_ascii_case(&self.country_code)),
+            _ => false,
+        }
+    }
+}
+
+/// Named rule of a policy.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rule {
+    /// Name of the rule, reported when the rule fires.
+    pub name: String,
+    /// Condition that fires the rule.
+    pub condition: Condition,
+    /// Decision when the rule fires.
+    pub action: Decision,
+}
+
+/// Result of evaluating a policy.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Verdict {
+    /// Most severe decision of the fired rules, [`Decision::Accept`] if none fired.
+    pub decision: Decision,
+    /// Names of the fired rules.
+    pub fired_rules: Vec<String>,
+}
+
+/// Declarative acceptance policy for email, phone and IP verdicts.
+///
+/// A policy can be built in code or loaded from TOML (with the `toml` feature):
+///
+/// ```toml
+/// [[rules]]
+/// name = "disposable-email"
+/// condition = "disposable_email"
+/// action = "reject"
+///
+/// [[rules]]
+/// name = "low-quality-email"
+/// condition = { email_quality_below = 0.5 }
+/// action = "review"
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Policy {
+    /// Rules of the policy.
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+impl Policy {
+    /// Creates a new policy without rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a policy from TOML.
+    #[cfg(feature = "toml")]
+    pub fn from_toml<S: AsRef<str>>(policy: S) -> Result<Self> {
+        toml::from_str(policy.as_ref()).map_err(|e| Error::InvalidPolicy(e.to_string()))
+    }
+
+    /// Adds a rule to the policy.
+    pub fn add_rule<S: Into<String>>(&mut self, name: S, condition: Condition, action: Decision) {
+        self.rules.push(Rule {
+            name: name.into(),
+            condition,
+            action,
+        });
+    }
+
+    /// Evaluates the API result and returns the verdict.
+    pub fn evaluate<T: PolicySubject>(&self, subject: &T) -> Verdict {
+        self.rules
+            .iter()
+            .filter(|rule| subject.matches(&rule.condition))
+            .fold(Verdict::default(), |mut verdict, rule| {
+                verdict.decision = verdict.decision.max(rule.action);
+                verdict.fired_rules.push(rule.name.to_string());
+                verdict
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{EmailFlag, Security};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_evaluate() {
+        let mut policy = Policy::new();
+        policy.add_rule("disposable", Condition::DisposableEmail, Decision::Reject);
+        policy.add_rule("role", Condition::RoleEmail, Decision::Review);
+        policy.add_rule("vpn", Condition::Vpn, Decision::Reject);
+        let email_details = EmailDetails {
+            is_role_email: EmailFlag {
+                value: true,
+                ..EmailFlag::default()
+            },
+            ..EmailDetails::default()
+        };
+        assert_eq!(
+            Verdict {
+                decision: Decision::Review,
+                fired_rules: vec![String::from("role")],
+            },
+            policy.evaluate(&email_details)
+        );
+        let geolocation = Geolocation {
+            security: Security { is_vpn: true },
+            ..Geolocation::default()
+        };
+        assert_eq!(Decision::Reject, policy.evaluate(&geolocation).decision);
+        assert_eq!(
+            Decision::Accept,
+            Policy::new().evaluate(&geolocation).decision
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml() -> Result<()> {
+        let policy = Policy::from_toml(
+            r#"
+            [[rules]]
+            name = "disposable-email"
+            condition = "disposable_email"
+            action = "reject"
+
+            [[rules]]
+            name = "undeliverable-email"
+            condition = { email_deliverability = ["UNDELIVERABLE", "RISKY"] }
+            action = "review"
+            "#,
+        )?;
+        assert_eq!(
+            Condition::EmailDeliverability(vec![
+                Deliverability::Undeliverable,
+                Deliverability::Risky
+            ]),
+            policy.rules[1].condition
+        );
+        assert!(Policy::from_toml("rules = 1").is_err());
+        Ok(())
+    }
+}
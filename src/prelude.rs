@@ -1,3 +1,4 @@
 pub use super::api::*;
 pub use super::error::Error as AbstractApiError;
+pub use super::rounding::RoundingMode;
 pub use super::*;
@@ -0,0 +1,37 @@
+use std::sync::{Mutex, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Rate limiter that spaces out consecutive requests by a fixed interval.
+///
+/// The limiter is shared between threads, so concurrent callers are queued.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next_request: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter with the given interval between requests.
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next_request: Mutex::new(None),
+        }
+    }
+
+    /// Blocks the current thread until the next request is allowed.
+    pub(crate) fn wait(&self) {
+        let mut next_request = self
+            .next_request
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(next_request) = *next_request {
+            let now = Instant::now();
+            if next_request > now {
+                thread::sleep(next_request - now);
+            }
+        }
+        *next_request = Some(Instant::now() + self.interval);
+    }
+}
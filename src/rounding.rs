@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+/// Tolerance used for detecting ties and integers after scaling.
+///
+/// Decimal amounts such as `1.005` are not exactly representable as [`f64`],
+/// so the scaled value is compared against this tolerance instead of `0.5`.
+const EPSILON: f64 = 1e-9;
+
+/// Strategy for rounding monetary amounts to a number of decimal places.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// Round half away from zero (commercial rounding).
+    #[default]
+    HalfUp,
+    /// Round half to the nearest even digit (banker's rounding).
+    HalfEven,
+    /// Round towards zero (truncate).
+    Down,
+    /// Round away from zero.
+    Up,
+}
+
+impl RoundingMode {
+    /// Rounds the given value to the given number of decimal places.
+    pub fn round(self, value: f64, decimals: u32) -> f64 {
+        if !value.is_finite() {
+            return value;
+        }
+        let factor = 10_f64.powi(decimals as i32);
+        let scaled = value * factor;
+        let tolerance = EPSILON * scaled.abs().max(1.);
+        let (integer, fraction) = (scaled.trunc(), scaled.fract().abs());
+        let rounded = if fraction < tolerance {
+            integer
+        } else if 1. - fraction < tolerance {
+            integer + scaled.signum()
+        } else {
+            match self {
+                Self::HalfUp if (fraction - 0.5).abs() < tolerance => integer + scaled.signum(),
+                Self::HalfEven if (fraction - 0.5).abs() < tolerance => {
+                    if integer % 2. == 0. {
+                        integer
+                    } else {
+                        integer + scaled.signum()
+                    }
+                }
+                Self::HalfUp | Self::HalfEven => scaled.round(),
+                Self::Down => integer,
+                Self::Up => integer + scaled.signum(),
+            }
+        };
+        rounded / factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_round() {
+        for (mode, value, expected) in [
+            (RoundingMode::HalfUp, 1.005, 1.01),
+            (RoundingMode::HalfUp, -1.005, -1.01),
+            (RoundingMode::HalfUp, 2.675, 2.68),
+            (RoundingMode::HalfUp, 1.004, 1.),
+            (RoundingMode::HalfEven, 1.005, 1.),
+            (RoundingMode::HalfEven, 1.015, 1.02),
+            (RoundingMode::HalfEven, -1.025, -1.02),
+            (RoundingMode::HalfEven, 1.0051, 1.01),
+            (RoundingMode::Down, 1.019, 1.01),
+            (RoundingMode::Down, -1.019, -1.01),
+            (RoundingMode::Down, 1.1, 1.1),
+            (RoundingMode::Up, 1.001, 1.01),
+            (RoundingMode::Up, -1.001, -1.01),
+            (RoundingMode::Up, 1.1, 1.1),
+        ] {
+            assert_eq!(expected, mode.round(value, 2), "{:?} {}", mode, value);
+        }
+        assert_eq!(-2., RoundingMode::HalfEven.round(-2.5, 0));
+        assert_eq!(-3., RoundingMode::HalfUp.round(-2.5, 0));
+        assert!(RoundingMode::Up.round(f64::NAN, 2).is_nan());
+    }
+}
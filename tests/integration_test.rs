@@ -73,7 +73,7 @@ fn test_company_enrichment_api() -> TestResult {
 
     sleep();
     let company_details: CompanyDetails =
-        abstractapi.get_company_details(Some("google.com"), None)?;
+        abstractapi.get_company_details(CompanyLookup::Domain(String::from("google.com")))?;
     assert_eq!(Some(1998), company_details.year_founded);
     assert_eq!(Some("United States"), company_details.country.as_deref());
     Ok(())
@@ -113,14 +113,27 @@ fn test_email_validation_api() -> TestResult {
 
     sleep();
     let email_result: EmailDetails = abstractapi.validate_email("test@gmial.com", true)?;
-    assert_eq!("test@gmail.com", email_result.autocorrect);
-    assert_eq!("UNDELIVERABLE", email_result.deliverability);
+    assert_eq!(Some("test@gmail.com"), email_result.autocorrect.as_deref());
+    assert_eq!(Deliverability::Undeliverable, email_result.deliverability);
     assert!(email_result.is_valid_format.value);
 
     sleep();
     let email_result: EmailDetails = abstractapi.validate_email("test@yopmail.com", true)?;
     assert!(email_result.is_disposable_email.value);
 
+    let mut abstractapi = abstractapi;
+    abstractapi.set_api_version(ApiType::EmailValidation, ApiVersion::V2);
+    sleep();
+    let email_result: EmailDetails = abstractapi.validate_email("test@gmial.com", true)?;
+    assert_eq!(Some("test@gmail.com"), email_result.autocorrect.as_deref());
+
+    abstractapi.set_api_version(ApiType::EmailValidation, ApiVersion(3));
+    abstractapi.set_email_precheck(EmailPrecheck::default());
+    assert!(matches!(
+        abstractapi.validate_email("test@yopmail.com", false),
+        Err(AbstractApiError::UnsupportedApiVersion(version)) if version == "emailvalidation/v3"
+    ));
+
     Ok(())
 }
 
@@ -132,11 +145,11 @@ fn test_phone_validation_api() -> TestResult {
     )?;
 
     sleep();
-    let phone_result: PhoneDetails = abstractapi.validate_phone("14152007986")?;
+    let phone_result: PhoneDetails = abstractapi.validate_phone("14152007986", None)?;
     assert!(phone_result.valid);
     assert_eq!("US", phone_result.country.code);
     assert_eq!("California", phone_result.location);
-    assert_eq!("mobile", phone_result.type_);
+    assert_eq!(LineType::Mobile, phone_result.type_);
 
     Ok(())
 }
@@ -156,14 +169,164 @@ fn test_vat_api() -> TestResult {
 
     sleep();
     let vat = abstractapi.calculate_vat(200., "DE", false, None)?;
-    assert_eq!("standard", vat.vat_category);
+    assert_eq!(VatCategory::Standard, vat.vat_category);
+    assert_eq!(200., vat.amount_excluding_vat);
     assert_eq!("DE", vat.country.code);
 
     sleep();
     let vat_rates = abstractapi.get_vat_rates("DE")?;
     assert!(vat_rates
         .iter()
-        .any(|vat_rate| vat_rate.category == "books"));
+        .any(|vat_rate| vat_rate.category == VatCategory::Books));
+    assert!(vat_rates.for_category(&VatCategory::Books).is_some());
+
+    Ok(())
+}
+
+#[test]
+fn test_iban_validation_api() -> TestResult {
+    let abstractapi = AbstractApi::new_with_api_key(
+        ApiType::IbanValidation,
+        env::var("IBAN_VALIDATION_API_KEY").expect("IBAN_VALIDATION_API_KEY is not set"),
+    )?;
+
+    sleep();
+    let iban_result: IbanDetails = abstractapi.validate_iban("BE71 0961 2345 6769")?;
+    assert!(iban_result.is_valid);
+    assert_eq!("BE71096123456769", iban_result.iban);
+
+    assert!(abstractapi.validate_iban("BE71096123456768").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_website_screenshot_api() -> TestResult {
+    let abstractapi = AbstractApi::new_with_api_key(
+        ApiType::WebsiteScreenshot,
+        env::var("WEBSITE_SCREENSHOT_API_KEY").expect("WEBSITE_SCREENSHOT_API_KEY is not set"),
+    )?;
+
+    sleep();
+    let options = ScreenshotOptions {
+        width: Some(800),
+        height: Some(600),
+        export_format: Some(ExportFormat::Png),
+        ..ScreenshotOptions::default()
+    };
+    let screenshot = abstractapi.capture_screenshot("https://example.com", &options)?;
+    assert_eq!("image/png", screenshot.content_type);
+    assert!(screenshot.data.starts_with(b"\x89PNG"));
+
+    sleep();
+    let mut image = Vec::new();
+    let content_type =
+        abstractapi.capture_screenshot_to("https://example.com", &options, &mut image)?;
+    assert_eq!("image/png", content_type);
+    assert!(!image.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_web_scraping_api() -> TestResult {
+    let abstractapi = AbstractApi::new_with_api_key(
+        ApiType::WebScraping,
+        env::var("WEB_SCRAPING_API_KEY").expect("WEB_SCRAPING_API_KEY is not set"),
+    )?;
+
+    sleep();
+    let options = ScrapeOptions {
+        render_js: Some(false),
+        headers: vec![(String::from("Accept-Language"), String::from("en-US"))],
+        ..ScrapeOptions::default()
+    };
+    let html = abstractapi.scrape("https://example.com", &options)?;
+    assert!(html.contains("Example Domain"));
+
+    Ok(())
+}
+
+#[test]
+fn test_image_processing_api() -> TestResult {
+    let abstractapi = AbstractApi::new_with_api_key(
+        ApiType::ImageProcessing,
+        env::var("IMAGE_PROCESSING_API_KEY").expect("IMAGE_PROCESSING_API_KEY is not set"),
+    )?;
+
+    sleep();
+    let mut options = ImageOptions::new();
+    options.set_resize(Resize {
+        width: Some(100),
+        strategy: ResizeStrategy::Landscape,
+        ..Resize::default()
+    });
+    let image = abstractapi.process_image(
+        &ImageSource::Url(String::from(
+            "https://s3.amazonaws.com/static.abstractapi.com/test-images/dog.jpg",
+        )),
+        &options,
+    )?;
+    assert_eq!(100, image.final_width);
+    assert!(!image.url.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_avatars_api() -> TestResult {
+    let abstractapi = AbstractApi::new_with_api_key(
+        ApiType::Avatars,
+        env::var("AVATARS_API_KEY").expect("AVATARS_API_KEY is not set"),
+    )?;
+
+    sleep();
+    let options = AvatarOptions {
+        image_size: Some(64),
+        background_color: Some(String::from("#335eea")),
+        is_rounded: Some(true),
+        ..AvatarOptions::default()
+    };
+    let avatar = abstractapi.generate_avatar("Orhun Parmaksiz", &options)?;
+    assert_eq!("image/png", avatar.content_type);
+    assert!(avatar.data.starts_with(b"\x89PNG"));
+
+    let options = AvatarOptions {
+        char_limit: Some(5),
+        ..AvatarOptions::default()
+    };
+    assert!(abstractapi.generate_avatar("Orhun", &options).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_ip_intelligence_api() -> TestResult {
+    let abstractapi = AbstractApi::new_with_api_key(
+        ApiType::IpIntelligence,
+        env::var("IP_INTELLIGENCE_API_KEY").expect("IP_INTELLIGENCE_API_KEY is not set"),
+    )?;
+
+    sleep();
+    let ip_intelligence: IpIntelligence = abstractapi.get_ip_intelligence("172.217.19.142")?;
+    assert_eq!("172.217.19.142", ip_intelligence.ip_address);
+    assert_eq!(Some(15169), ip_intelligence.asn.asn);
+    assert!(!ip_intelligence.security.is_tor);
+
+    Ok(())
+}
+
+#[test]
+fn test_email_reputation_api() -> TestResult {
+    let abstractapi = AbstractApi::new_with_api_key(
+        ApiType::EmailReputation,
+        env::var("EMAIL_REPUTATION_API_KEY").expect("EMAIL_REPUTATION_API_KEY is not set"),
+    )?;
+
+    sleep();
+    let email_reputation: EmailReputation = abstractapi.get_email_reputation("test@yopmail.com")?;
+    assert_eq!("test@yopmail.com", email_reputation.email_address);
+    assert_eq!(Some(true), email_reputation.email_quality.is_disposable);
 
     Ok(())
 }